@@ -0,0 +1,122 @@
+use std::fmt::Display;
+
+/// The result of a part, AoC answers are usually integers but not always.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Int(i64),
+    /// Unsigned values too big for an `i64`
+    Big(u128),
+    Str(String),
+}
+
+impl Answer {
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(n) => Some(*n),
+            Self::Big(n) => i64::try_from(*n).ok(),
+            Self::Str(_) => None,
+        }
+    }
+
+    /// Reads integers as numbers and anything else as a string answer.
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if let Ok(n) = s.parse::<i64>() {
+            Self::Int(n)
+        } else if let Ok(n) = s.parse::<u128>() {
+            Self::Big(n)
+        } else {
+            Self::Str(s.to_string())
+        }
+    }
+
+    fn as_u128(&self) -> Option<u128> {
+        match self {
+            Self::Int(n) => u128::try_from(*n).ok(),
+            Self::Big(n) => Some(*n),
+            Self::Str(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{}", n),
+            Self::Big(n) => write!(f, "{}", n),
+            Self::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Str(a), Self::Str(b)) => a == b,
+            (Self::Str(_), _) | (_, Self::Str(_)) => false,
+            (Self::Int(a), Self::Int(b)) => a == b,
+            // Big and Int can hold the same value, compare them numerically
+            _ => self.as_u128().is_some() && self.as_u128() == other.as_u128(),
+        }
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        self.as_i64() == Some(*other)
+    }
+}
+
+impl PartialEq<Answer> for i64 {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Self::Str(s) if s == other)
+    }
+}
+
+macro_rules! answer_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Int(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! answer_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Self::Int(n),
+                        Err(_) => Self::Big(n as u128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_signed!(i32, i64, isize);
+answer_from_unsigned!(u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Str(s.to_string())
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answer::Answer, day::LEGACY_YEAR};
+
+pub const ANSWERS_ENV: &str = "ADVENT_ANSWERS";
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+const HEADER: &str =
+    "# Confirmed answers for the puzzle inputs, `advent_2023 record` keeps this file up to date\n";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        line: usize,
+        cause: String,
+    },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "Failed to access answers {}: {}", path.display(), source)
+            }
+            Self::Parse { path, line, cause } => {
+                write!(f, "Invalid answers {}:{}: {}", path.display(), line, cause)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong { .. } => write!(f, "wrong"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Known answers keyed by `(year, day, part)`, stored as a small TOML file:
+///
+/// ```toml
+/// [2023.day1]
+/// part1 = 55208
+/// part2 = "text answers are quoted"
+/// ```
+///
+/// Sections without a year, like `[day1]`, are from before there were years
+/// and belong to [`LEGACY_YEAR`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32, u32), Answer>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, (usize, String)> {
+        let mut answers = BTreeMap::new();
+        let mut section = None;
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let section_header = header.strip_suffix(']').map(str::trim).and_then(|h| {
+                    let (year, day) = match h.split_once('.') {
+                        Some((year, day)) => (year.trim().parse::<u32>().ok()?, day.trim()),
+                        None => (LEGACY_YEAR, h),
+                    };
+                    Some((year, day.strip_prefix("day")?.parse::<u32>().ok()?))
+                });
+                section = Some(
+                    section_header
+                        .ok_or((i + 1, format!("expected [YEAR.dayN], found {}", line)))?,
+                );
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or((i + 1, format!("expected partN = answer, found {}", line)))?;
+            let part = key
+                .trim()
+                .strip_prefix("part")
+                .and_then(|n| n.parse::<u32>().ok())
+                .ok_or((i + 1, format!("expected partN, found {}", key.trim())))?;
+            let (year, day) =
+                section.ok_or((i + 1, "answer outside of a [YEAR.dayN] section".to_string()))?;
+            let answer = parse_value(value.trim()).map_err(|e| (i + 1, e))?;
+
+            answers.insert((year, day, part), answer);
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// A missing file just means nothing has been recorded yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|(line, cause)| AnswersError::Parse {
+                path: path.to_path_buf(),
+                line,
+                cause,
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AnswersError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        std::fs::write(path, self.to_toml()).map_err(|source| AnswersError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut out = HEADER.to_string();
+        let mut current_day = None;
+        for (&(year, day, part), answer) in &self.answers {
+            if current_day != Some((year, day)) {
+                out += &format!("\n[{}.day{}]\n", year, day);
+                current_day = Some((year, day));
+            }
+            let value = match answer {
+                Answer::Str(s) => format!(
+                    "\"{}\"",
+                    s.replace('\\', "\\\\")
+                        .replace('"', "\\\"")
+                        .replace('\n', "\\n")
+                ),
+                answer => answer.to_string(),
+            };
+            out += &format!("part{} = {}\n", part, value);
+        }
+        out
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&Answer> {
+        self.answers.get(&(year, day, part))
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: u32, answer: Answer) -> Option<Answer> {
+        self.answers.insert((year, day, part), answer)
+    }
+
+    /// Removes every recorded part of `day`, returning them by part.
+    pub fn remove_day(&mut self, year: u32, day: u32) -> Vec<(u32, Answer)> {
+        let parts = self
+            .answers
+            .range((year, day, 0)..=(year, day, u32::MAX))
+            .map(|(&(_, _, part), _)| part)
+            .collect::<Vec<u32>>();
+        parts
+            .into_iter()
+            .filter_map(|part| Some((part, self.answers.remove(&(year, day, part))?)))
+            .collect()
+    }
+
+    pub fn verify(&self, year: u32, day: u32, part: u32, answer: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+fn parse_value(value: &str) -> Result<Answer, String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let quoted = quoted
+            .strip_suffix('"')
+            .ok_or(format!("unterminated string {}", value))?;
+        let mut s = String::new();
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                s.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => s.push('\n'),
+                Some(c @ ('"' | '\\')) => s.push(c),
+                other => return Err(format!("invalid escape \\{}", other.unwrap_or(' '))),
+            }
+        }
+        return Ok(Answer::Str(s));
+    }
+
+    match Answer::parse(&value.replace('_', "")) {
+        Answer::Str(_) => Err(format!(
+            "expected a number or a quoted string, found {}",
+            value
+        )),
+        answer => Ok(answer),
+    }
+}
+
+pub fn answers_path() -> PathBuf {
+    std::env::var_os(ANSWERS_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE))
+}
+
+pub fn load_answers() -> Result<Answers, AnswersError> {
+    Answers::load(&answers_path())
+}
+
+/// The recorded answer for a part, for use in tests.
+#[cfg(test)]
+pub fn expected(year: u32, day: u32, part: u32) -> Answer {
+    let answers = load_answers().unwrap_or_else(|e| panic!("{}", e));
+    answers
+        .get(year, day, part)
+        .cloned()
+        .unwrap_or_else(|| panic!("No answer recorded for {} day {} part {}", year, day, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(2023, 1, 1, Answer::Int(-55208));
+        answers.insert(2023, 1, 2, Answer::Str("say \"hi\"\\\nbye".to_string()));
+        answers.insert(2023, 25, 1, Answer::Big(u128::MAX));
+        answers.insert(2022, 3, 2, Answer::Str("123".to_string()));
+
+        let toml = answers.to_toml();
+        let parsed = Answers::parse(&toml).unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.to_toml(), toml);
+        assert!(matches!(parsed.get(2023, 25, 1), Some(Answer::Big(n)) if *n == u128::MAX));
+        assert!(matches!(parsed.get(2022, 3, 2), Some(Answer::Str(s)) if s == "123"));
+    }
+
+    #[test]
+    fn test_parse_values() {
+        let answers = Answers::parse(
+            "# comment\n[2023.day5]\npart1 = 1_000_000\npart2 = \"a\\\\b\\nc\"\n\n[ 2022.day7 ]\n  part1=42",
+        )
+        .unwrap();
+        assert_eq!(answers.get(2023, 5, 1), Some(&Answer::Int(1_000_000)));
+        assert_eq!(
+            answers.get(2023, 5, 2),
+            Some(&Answer::Str("a\\b\nc".to_string()))
+        );
+        assert_eq!(answers.get(2022, 7, 1), Some(&Answer::Int(42)));
+    }
+
+    #[test]
+    fn test_legacy_sections() {
+        let answers = Answers::parse("[day3]\npart1 = 4361\n[2022.day3]\npart1 = 1").unwrap();
+        assert_eq!(answers.get(LEGACY_YEAR, 3, 1), Some(&Answer::Int(4361)));
+        assert_eq!(answers.get(2022, 3, 1), Some(&Answer::Int(1)));
+        assert!(answers
+            .to_toml()
+            .contains(&format!("[{}.day3]", LEGACY_YEAR)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let line = |contents: &str| Answers::parse(contents).unwrap_err().0;
+        assert_eq!(line("part1 = 1"), 1);
+        assert_eq!(line("[2023.day1]\n\npart1 = abc"), 3);
+        assert_eq!(line("[2023.day1]\npart1 = \"open"), 2);
+        assert_eq!(line("[2023.day1]\npart1 = \"bad \\t escape\""), 2);
+        assert_eq!(line("[2023.day1]\npartx = 1"), 2);
+        assert_eq!(line("[2023.day1]\npart1 1"), 2);
+        assert_eq!(line("[2023.dayx]"), 1);
+        assert_eq!(line("[year.day1]"), 1);
+        assert_eq!(line("[2023.day1"), 1);
+    }
+}
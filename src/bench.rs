@@ -0,0 +1,280 @@
+use std::{
+    collections::HashMap,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    day::{self, Day, LEGACY_YEAR},
+    input::InputSource,
+    report::Nanos,
+    runner,
+};
+
+pub const DEFAULT_WARMUP: usize = 3;
+pub const DEFAULT_RUNS: usize = 20;
+/// Percentage the median may grow over the baseline before it counts as a regression
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+    pub source: InputSource,
+    pub save: Option<String>,
+    pub baseline: Option<String>,
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: DEFAULT_WARMUP,
+            runs: DEFAULT_RUNS,
+            source: InputSource::Default,
+            save: None,
+            baseline: None,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// All values are in nanoseconds.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let len = nanos.len().max(1) as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / len;
+        let median = match nanos.len() {
+            0 => 0.0,
+            n if n % 2 == 0 => (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0,
+            n => nanos[n / 2],
+        };
+
+        Self {
+            min: nanos.first().copied().unwrap_or_default(),
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+pub struct BenchResult {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub read: Stats,
+    /// Only for days with a [`Day::parse`] hook, the others have their
+    /// parsing timed as part of `solve`
+    pub parse: Option<Stats>,
+    /// The whole part less the parse time
+    pub solve: Stats,
+}
+
+fn bench_part(
+    year: u32,
+    day_num: u32,
+    part: u32,
+    day: &dyn Day,
+    options: &BenchOptions,
+) -> Result<BenchResult, String> {
+    let mut read = Vec::with_capacity(options.runs);
+    let mut parse = Vec::with_capacity(options.runs);
+    let mut solve = Vec::with_capacity(options.runs);
+    let mut has_parse = true;
+
+    for run in 0..options.warmup + options.runs {
+        let start = Instant::now();
+        let input = options
+            .source
+            .load(year, day_num, day)
+            .map_err(|e| e.to_string())?;
+        let loaded = Instant::now();
+        runner::solve(year, day_num, part, day, &input).map_err(|e| e.to_string())?;
+        let solved = Instant::now();
+        let parsed = match day.parse(part, &input) {
+            Some(result) => {
+                result.map_err(|e| e.to_string())?;
+                solved.elapsed()
+            }
+            None => {
+                has_parse = false;
+                Duration::ZERO
+            }
+        };
+
+        if run >= options.warmup {
+            read.push(loaded - start);
+            parse.push(parsed);
+            solve.push((solved - loaded).saturating_sub(parsed));
+        }
+    }
+
+    Ok(BenchResult {
+        year,
+        day: day_num,
+        part,
+        read: Stats::from_samples(&read),
+        parse: has_parse.then(|| Stats::from_samples(&parse)),
+        solve: Stats::from_samples(&solve),
+    })
+}
+
+/// Baseline files have one `year,day,part,median_ns` line per benchmarked part,
+/// lines without a year are from before there were years.
+fn read_baseline(path: &str) -> Result<HashMap<(u32, u32, u32), f64>, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read baseline {}: {}", path, e))?;
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|(i, l)| {
+            let mut fields = l.split(',').map(str::trim).collect::<Vec<&str>>();
+            let year = match fields.len() {
+                3 => LEGACY_YEAR,
+                _ => fields
+                    .remove(0)
+                    .parse()
+                    .map_err(|_| format!("{}:{}: invalid year", path, i + 1))?,
+            };
+            match fields[..] {
+                [day, part, median] => Ok((
+                    (
+                        year,
+                        day.parse()
+                            .map_err(|_| format!("{}:{}: invalid day", path, i + 1))?,
+                        part.parse()
+                            .map_err(|_| format!("{}:{}: invalid part", path, i + 1))?,
+                    ),
+                    median
+                        .parse()
+                        .map_err(|_| format!("{}:{}: invalid median", path, i + 1))?,
+                )),
+                _ => Err(format!(
+                    "{}:{}: expected year,day,part,median_ns",
+                    path,
+                    i + 1
+                )),
+            }
+        })
+        .collect()
+}
+
+fn write_baseline(path: &str, results: &[BenchResult]) -> Result<(), String> {
+    let mut contents = String::from("# year,day,part,median_ns\n");
+    for result in results {
+        contents += &format!(
+            "{},{},{},{:.0}\n",
+            result.year, result.day, result.part, result.solve.median
+        );
+    }
+    if let Some(dir) = Path::new(path).parent() {
+        if !dir.as_os_str().is_empty() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+    }
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write baseline {}: {}", path, e))
+}
+
+/// Benchmarks every `(year, day, part)` and returns whether all of them ran
+/// without errors or regressions.
+pub fn run(
+    selection: &[(u32, u32, u32)],
+    get_day: impl Fn(u32, u32) -> Box<dyn Day>,
+    options: &BenchOptions,
+) -> bool {
+    let baseline = match options.baseline.as_deref().map(read_baseline).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    println!(
+        "Benchmarking {} part(s), {} warmup and {} measured run(s) each",
+        selection.len(),
+        options.warmup,
+        options.runs
+    );
+
+    let mut ok = true;
+    let mut results = vec![];
+
+    for &(year, day_num, part) in selection {
+        if !day::has_part(day_num, part) {
+            println!("{} Day {} has no part {} to benchmark", year, day_num, part);
+            continue;
+        }
+        let day = get_day(year, day_num);
+        let result = match bench_part(year, day_num, part, day.as_ref(), options) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+                continue;
+            }
+        };
+
+        let comparison = baseline
+            .as_ref()
+            .and_then(|b| b.get(&(year, day_num, part)))
+            .map(|&before| {
+                let change = (result.solve.median - before) / before.max(1.0) * 100.0;
+                if change > options.threshold {
+                    ok = false;
+                    format!("  REGRESSION {:+.1}% vs {}", change, Nanos(before))
+                } else {
+                    format!("  {:+.1}% vs {}", change, Nanos(before))
+                }
+            })
+            .unwrap_or_default();
+
+        println!(
+            "{} Day {} Part {}: min {}  median {}  mean {}  stddev {}  (parse {}, read {}){}",
+            year,
+            day_num,
+            part,
+            Nanos(result.solve.min),
+            Nanos(result.solve.median),
+            Nanos(result.solve.mean),
+            Nanos(result.solve.stddev),
+            result
+                .parse
+                .map(|parse| Nanos(parse.median).to_string())
+                .unwrap_or_else(|| "included".to_string()),
+            Nanos(result.read.median),
+            comparison
+        );
+
+        results.push(result);
+    }
+
+    if let Some(path) = &options.save {
+        match write_baseline(path, &results) {
+            Ok(()) => println!("Saved baseline to {}", path),
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
@@ -1,67 +1,802 @@
-pub fn bootstrap_file(number: u32) {
-    let file_name = format!("src/inputs/day_{}.txt", number);
+use std::{
+    collections::HashMap,
+    io::Read,
+    path::{Path, PathBuf},
+};
 
-    if std::path::Path::new(&file_name).exists() {
-        println!("File {} already exists", file_name);
-        std::process::exit(1);
-    }
+use crate::{
+    answer::Answer,
+    answers::{answers_path, load_answers, Answers},
+    day::{self, LEGACY_YEAR},
+    input,
+};
+
+const SRC_DIR: &str = "src";
+const REGISTRY_FILE: &str = "src/registry.rs";
+
+pub const TEMPLATE_DIR_ENV: &str = "ADVENT_TEMPLATE_DIR";
+pub const DEFAULT_TEMPLATE_DIR: &str = "templates";
+pub const DEFAULT_TEMPLATE: &str = "basic";
 
-    std::fs::write(file_name, "").expect("Failed to write file");
+const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    ("basic", include_str!("templates/basic.rs")),
+    ("grid", include_str!("templates/grid.rs")),
+    ("lines", include_str!("templates/lines.rs")),
+    ("graph", include_str!("templates/graph.rs")),
+];
 
-    let template = include_str!("template.rs");
+const REGISTRY_HEADER: &str =
+    "// Generated by `advent_2023 bootstrap` from the src/year_*/day_*.rs files.
+// Titles can be edited, they're kept when the file is regenerated.
+";
+
+/// A puzzle example and the answers it should give, `FILE=P1,P2` on the
+/// command line. `-` reads the example from stdin and an empty answer skips
+/// that part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub path: String,
+    pub answers: [Option<Answer>; 2],
+}
 
-    let template = template.replace("_n_", &number.to_string());
+impl Example {
+    pub fn parse(arg: &str) -> Result<Self, String> {
+        let (path, answers) = arg
+            .rsplit_once('=')
+            .ok_or(format!("Expected FILE=P1,P2 for example {:?}", arg))?;
+        let path = path.trim();
+        if path.is_empty() {
+            return Err(format!("Missing file for example {:?}", arg));
+        }
 
-    let file_name = format!("src/day_{}.rs", number);
+        let answers = answers.split(',').map(str::trim).collect::<Vec<&str>>();
+        if answers.len() > 2 {
+            return Err(format!(
+                "Expected at most two answers for example {:?}",
+                arg
+            ));
+        }
+        let answer = |i: usize| {
+            answers
+                .get(i)
+                .filter(|a| !a.is_empty())
+                .map(|a| Answer::parse(a))
+        };
+        let answers = [answer(0), answer(1)];
+        if answers.iter().all(Option::is_none) {
+            return Err(format!("Missing answers for example {:?}", arg));
+        }
 
-    if std::path::Path::new(&file_name).exists() {
-        println!("File {} already exists", file_name);
-        std::process::exit(1);
+        Ok(Self {
+            path: path.to_string(),
+            answers,
+        })
+    }
+
+    fn read(&self) -> Result<String, String> {
+        let mut contents = String::new();
+        if self.path == "-" {
+            std::io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(|e| format!("Failed to read example from stdin: {}", e))?;
+        } else {
+            contents = std::fs::read_to_string(&self.path)
+                .map_err(|e| format!("Failed to read example {}: {}", self.path, e))?;
+        }
+        Ok(input::normalize(&contents))
     }
+}
 
-    std::fs::write(file_name, template).expect("Failed to write file");
+fn answer_literal(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(n) => format!("{}i64", n),
+        Answer::Big(n) => format!("crate::answer::Answer::Big({})", n),
+        Answer::Str(s) => format!("{:?}", s),
+    }
+}
 
-    let main_file = include_str!("main.rs");
+/// The example constants and their tests, to go in the tests module of day `number`.
+fn render_examples(number: u32, examples: &[(String, &Example)]) -> String {
+    let mut out = String::new();
+    for (i, (contents, example)) in examples.iter().enumerate() {
+        let n = i + 1;
+        // Enough hashes that nothing in the example can end the raw string
+        let hashes = "#".repeat(
+            contents
+                .split('"')
+                .skip(1)
+                .map(|s| s.len() - s.trim_start_matches('#').len() + 1)
+                .max()
+                .unwrap_or(0)
+                .max(1),
+        );
+        out += &format!("\n    const EXAMPLE_{n}: &str = r{hashes}\"{contents}\"{hashes};\n");
+        for (part, answer) in (1..).zip(&example.answers) {
+            let Some(answer) = answer else { continue };
+            out += &format!(
+                "\n    #[test]\n    fn test_example_{n}_part_{part}() {{\n        assert_eq!(Day{number}.part_{part}(EXAMPLE_{n}).unwrap(), {});\n    }}\n",
+                answer_literal(answer)
+            );
+        }
+    }
+    out
+}
 
-    // Let's do smth a bit silly
+pub fn template_dir() -> PathBuf {
+    std::env::var_os(TEMPLATE_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_TEMPLATE_DIR))
+}
 
-    let yesterday = number - 1;
+/// Looks for `NAME.rs` in the template directory first, so the built-in
+/// templates can be overridden, then falls back to the built-in ones.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = template_dir().join(format!("{}.rs", name));
+    match std::fs::read_to_string(&path) {
+        Ok(template) => return Ok(template),
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            return Err(format!("Failed to read {}: {}", path.display(), e))
+        }
+        Err(_) => {}
+    }
 
-    let main_file = main_file.replace(
-        &format!(", day_{yesterday}"),
-        &format!(", day_{yesterday}, day_{number}"),
-    );
-    let main_file = main_file.replace(
-        &format!("use day_{yesterday}::Day{yesterday};"),
-        &format!("use day_{yesterday}::Day{yesterday};\nuse day_{number}::Day{number};"),
-    );
-    let main_file = main_file.replace(
-        &format!("{yesterday} => Box::new(Day{yesterday}),"),
-        &format!(
-            "{yesterday} => Box::new(Day{yesterday}),\n\t\t{number} => Box::new(Day{number}),"
-        ),
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| template.to_string())
+        .ok_or_else(|| {
+            let names = BUILTIN_TEMPLATES
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<&str>>();
+            format!(
+                "Unknown template {:?}, expected one of {} or a .rs file in {}",
+                name,
+                names.join(", "),
+                template_dir().display()
+            )
+        })
+}
+
+/// The raw text of every template, user ones first.
+fn all_templates() -> Vec<String> {
+    let mut templates = std::fs::read_dir(template_dir())
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    (path.extension()? == "rs")
+                        .then(|| std::fs::read_to_string(path).ok())
+                        .flatten()
+                })
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default();
+    templates.extend(
+        BUILTIN_TEMPLATES
+            .iter()
+            .map(|(_, template)| template.to_string()),
     );
-    let main_file = main_file.replace(&format!("..={yesterday}"), &format!("..={number}"));
-
-    std::fs::write("src/main.rs", main_file).expect("Failed to write file");
-}
-
-pub fn get_next_highest_day() -> u32 {
-    std::fs::read_dir("src")
-        .unwrap()
-        .filter_map(|p| {
-            let p = p.unwrap().path();
-            let p = p
-                .file_name()
-                .unwrap()
-                .to_str()
-                .unwrap()
-                .split('.')
-                .next()
-                .unwrap();
-            p.strip_prefix("day_").map(|p| p.parse::<u32>().unwrap())
+    templates
+}
+
+fn substitute(template: &str, year: u32, number: u32, title: &str) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &number.to_string())
+        .replace("{{struct}}", &format!("Day{}", number))
+        .replace("{{title}}", title)
+}
+
+/// Whether `contents` is still what one of the templates generated for the
+/// day, example tests included.
+fn is_untouched(contents: &str, year: u32, number: u32, title: &str) -> bool {
+    all_templates().iter().any(|template| {
+        let mut rendered = substitute(template, year, number, title);
+        if !day::has_part(number, 2) {
+            rendered = drop_part_2_test(rendered);
+        }
+        let Some(placeholder) = rendered
+            .lines()
+            .find(|line| line.trim() == "{{examples}}")
+            .map(|line| format!("{}\n", line))
+        else {
+            return contents == rendered;
+        };
+        match rendered.split_once(&placeholder) {
+            Some((before, after)) => contents
+                .strip_prefix(before)
+                .and_then(|rest| rest.strip_suffix(after))
+                .is_some_and(|examples| {
+                    examples.is_empty() || examples.starts_with("\n    const EXAMPLE_1: &str")
+                }),
+            None => contents == rendered,
+        }
+    })
+}
+
+/// Fills in the template variables:
+///
+/// - `{{year}}`: the year
+/// - `{{day}}`: the day number
+/// - `{{struct}}`: the name of the day struct, `DayN`
+/// - `{{title}}`: the puzzle title, empty if none was given
+/// - `{{examples}}`: the example tests, on a line of its own in the tests module
+fn render_template(
+    template: &str,
+    year: u32,
+    number: u32,
+    title: &str,
+    examples: &[(String, &Example)],
+) -> Result<String, String> {
+    let rendered = substitute(template, year, number, title);
+
+    let placeholder = rendered
+        .lines()
+        .find(|line| line.trim() == "{{examples}}")
+        .map(|line| format!("{}\n", line));
+    // The examples go in last so their contents are left alone
+    let rendered = match placeholder {
+        Some(placeholder) => rendered.replacen(&placeholder, &render_examples(number, examples), 1),
+        None if examples.is_empty() => rendered,
+        None => return Err("The template has no {{examples}} line for the examples".to_string()),
+    };
+
+    if !defines_day(&rendered, number) {
+        return Err(format!("The template doesn't define Day{}", number));
+    }
+    if day::has_part(number, 2) {
+        Ok(rendered)
+    } else {
+        Ok(drop_part_2_test(rendered))
+    }
+}
+
+/// Takes the real input test for part 2 out of a day without one, it could
+/// never pass. Only finds the test as the built-in templates write it.
+fn drop_part_2_test(rendered: String) -> String {
+    let start = "\n    #[test]\n    fn test_part_2() {";
+    let end = "\n    }\n";
+    match rendered.find(start) {
+        Some(i) => match rendered[i..].find(end) {
+            Some(len) => format!("{}{}", &rendered[..i], &rendered[i + len + end.len()..]),
+            None => rendered,
+        },
+        None => rendered,
+    }
+}
+
+/// Puts back the real input test for part 2 after the one for part 1, for a
+/// day moved off the last one. Only finds the tests as the built-in templates
+/// write them.
+fn add_part_2_test(contents: String, year: u32, number: u32) -> String {
+    let start = "\n    #[test]\n    fn test_part_1() {";
+    let end = "\n    }\n";
+    if contents.contains("fn test_part_2() {") {
+        return contents;
+    }
+    match contents.find(start) {
+        Some(i) => match contents[i..].find(end) {
+            Some(len) => {
+                let (before, after) = contents.split_at(i + len + end.len());
+                format!(
+                    "{}\n    #[test]\n    fn test_part_2() {{\n        let day = Day{number};\n        let input = day.get_input().unwrap();\n        assert_eq!(day.part_2(&input).unwrap(), answers::expected({year}, {number}, 2));\n    }}\n{}",
+                    before, after
+                )
+            }
+            None => contents,
+        },
+        None => contents,
+    }
+}
+
+/// Whether `contents` has a `struct DayN`, and not just one starting with it.
+fn defines_day(contents: &str, day: u32) -> bool {
+    let name = format!("struct Day{}", day);
+    contents.match_indices(&name).any(|(i, _)| {
+        !contents[i + name.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+    })
+}
+
+fn parse_suffixed(name: &std::ffi::OsStr, prefix: &str, suffix: &str) -> Option<u32> {
+    name.to_str()?
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// The `(year, day)` of every `year_YEAR/day_N.rs` file in `src`, sorted.
+fn days_on_disk() -> Result<Vec<(u32, u32)>, String> {
+    let entries =
+        std::fs::read_dir(SRC_DIR).map_err(|e| format!("Failed to read {}: {}", SRC_DIR, e))?;
+
+    let mut days = vec![];
+    for entry in entries.filter_map(Result::ok) {
+        let Some(year) = parse_suffixed(&entry.file_name(), "year_", "") else {
+            continue;
+        };
+        let dir = entry.path();
+        let files = std::fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+        days.extend(
+            files
+                .filter_map(|file| parse_suffixed(&file.ok()?.file_name(), "day_", ".rs"))
+                .map(|day| (year, day)),
+        );
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+fn year_dir(year: u32) -> PathBuf {
+    Path::new(SRC_DIR).join(format!("year_{}", year))
+}
+
+fn day_file(year: u32, number: u32) -> PathBuf {
+    year_dir(year).join(format!("day_{}.rs", number))
+}
+
+/// Reads the titles out of an existing registry so regenerating keeps them.
+fn registered_titles(registry: &str) -> HashMap<(u32, u32), String> {
+    let mut year = LEGACY_YEAR;
+    registry
+        .lines()
+        .filter_map(|line| {
+            if let Some((y, _)) = line.trim().split_once(" => ") {
+                year = y.parse().ok()?;
+                return None;
+            }
+            let (number, rest) = line.trim().split_once(':')?;
+            let title = &rest[rest.find('"')? + 1..rest.rfind('"')?];
+            Some((
+                (year, number.parse().ok()?),
+                title.replace("\\\"", "\"").replace("\\\\", "\\"),
+            ))
+        })
+        .collect()
+}
+
+fn render_registry(days: &[(u32, u32)], titles: &HashMap<(u32, u32), String>) -> String {
+    let mut registry = format!("{}register_days! {{\n", REGISTRY_HEADER);
+    let mut years = days.iter().map(|&(year, _)| year).collect::<Vec<u32>>();
+    years.dedup();
+    for year in years {
+        registry += &format!("    {year} => year_{year} {{\n");
+        for &(_, day) in days.iter().filter(|&&(y, _)| y == year) {
+            let title = titles
+                .get(&(year, day))
+                .map(String::as_str)
+                .unwrap_or_default();
+            registry += &format!("        {day}: day_{day}::Day{day} {:?},\n", title);
+        }
+        registry += "    },\n";
+    }
+    registry += "}\n";
+    registry
+}
+
+/// A change to the days on disk that hasn't been made yet, each starting
+/// with the year it's in.
+enum Change<'a> {
+    Add(u32, u32, &'a str),
+    Remove(u32, u32),
+    Renumber(u32, u32, u32),
+}
+
+/// The registry for every day on disk once `change` is made.
+fn plan_registry(change: Option<Change>) -> Result<String, String> {
+    let mut days = days_on_disk()?;
+
+    for &(y, day) in &days {
+        let path = day_file(y, day);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        if !defines_day(&contents, day) {
+            return Err(format!("{} doesn't define Day{}", path.display(), day));
+        }
+    }
+
+    let existing = std::fs::read_to_string(REGISTRY_FILE).unwrap_or_default();
+    let mut titles = registered_titles(&existing);
+
+    match change {
+        Some(Change::Add(year, day, title)) => {
+            days.push((year, day));
+            if !title.is_empty() {
+                titles.insert((year, day), title.to_string());
+            }
+        }
+        Some(Change::Remove(year, day)) => {
+            days.retain(|&d| d != (year, day));
+            titles.remove(&(year, day));
+        }
+        Some(Change::Renumber(year, from, to)) => {
+            days.retain(|&d| d != (year, from));
+            days.push((year, to));
+            if let Some(title) = titles.remove(&(year, from)) {
+                titles.insert((year, to), title);
+            }
+        }
+        None => {}
+    }
+    days.sort_unstable();
+
+    Ok(render_registry(&days, &titles))
+}
+
+fn write_registry(registry: &str, dry_run: bool) -> Result<(), String> {
+    let existing = std::fs::read_to_string(REGISTRY_FILE).unwrap_or_default();
+    if existing == registry {
+        println!("{} is up to date", REGISTRY_FILE);
+    } else if dry_run {
+        println!("Would write {}:\n{}", REGISTRY_FILE, registry);
+    } else {
+        std::fs::write(REGISTRY_FILE, registry)
+            .map_err(|e| format!("Failed to write {}: {}", REGISTRY_FILE, e))?;
+        println!("Updated {}", REGISTRY_FILE);
+    }
+    Ok(())
+}
+
+/// Regenerates the registry from the day files without creating anything.
+pub fn sync_registry(dry_run: bool) -> Result<(), String> {
+    write_registry(&plan_registry(None)?, dry_run)
+}
+
+/// Creates `day_N.rs` from a template, with tests for any examples, and an
+/// empty input, then regenerates the registry. Nothing is written unless all of
+/// it can be done.
+pub fn bootstrap_file(
+    year: u32,
+    number: u32,
+    title: &str,
+    template: &str,
+    examples: &[Example],
+    dry_run: bool,
+) -> Result<(), String> {
+    let day_path = day_file(year, number);
+    if day_path.exists() {
+        return Err(format!("{} already exists", day_path.display()));
+    }
+    if examples.iter().any(|e| e.answers[1].is_some()) && !day::has_part(number, 2) {
+        return Err(format!("Day {} has no part 2 to give examples for", number));
+    }
+
+    let registry = plan_registry(Some(Change::Add(year, number, title)))?;
+    let template = load_template(template)?;
+    let examples = examples
+        .iter()
+        .map(|example| Ok((example.read()?, example)))
+        .collect::<Result<Vec<_>, String>>()?;
+    let template = render_template(&template, year, number, title, &examples)
+        .map_err(|e| format!("{}: {}", day_path.display(), e))?;
+    let example_tests = examples
+        .iter()
+        .flat_map(|(_, example)| example.answers.iter().flatten())
+        .count();
+
+    let input_path = input::day_input_path(year, number);
+    let create_input = !input_path.exists();
+
+    if dry_run {
+        println!(
+            "Would create {} with {} example test(s)",
+            day_path.display(),
+            example_tests
+        );
+        if create_input {
+            println!("Would create {}", input_path.display());
+        }
+        return write_registry(&registry, true);
+    }
+
+    std::fs::create_dir_all(year_dir(year))
+        .map_err(|e| format!("Failed to create {}: {}", year_dir(year).display(), e))?;
+    std::fs::write(&day_path, template)
+        .map_err(|e| format!("Failed to write {}: {}", day_path.display(), e))?;
+    println!("Created {}", day_path.display());
+
+    if create_input {
+        if let Some(dir) = input_path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        std::fs::write(&input_path, "")
+            .map_err(|e| format!("Failed to write {}: {}", input_path.display(), e))?;
+        println!("Created {}", input_path.display());
+    } else {
+        println!("Keeping existing {}", input_path.display());
+    }
+
+    write_registry(&registry, false)
+}
+
+/// Replaces the references to day `from` that bootstrapping put in a day file,
+/// and drops or adds the part 2 test when the move changes the parts.
+fn renumber_contents(contents: &str, year: u32, from: u32, to: u32) -> String {
+    let name = format!("Day{}", from);
+    let mut renamed = String::with_capacity(contents.len());
+    let mut rest = contents;
+    while let Some(i) = rest.find(&name) {
+        let end = i + name.len();
+        let whole_word = !rest[end..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+            && !rest[..i].ends_with(|c: char| c.is_alphanumeric() || c == '_');
+        renamed += &rest[..i];
+        renamed += &if whole_word {
+            format!("Day{}", to)
+        } else {
+            name.clone()
+        };
+        rest = &rest[end..];
+    }
+    renamed += rest;
+
+    let renamed = renamed
+        .replace(
+            &format!("get_input_for_day!({})", from),
+            &format!("get_input_for_day!({})", to),
+        )
+        .replace(
+            &format!("answers::expected({}, {},", year, from),
+            &format!("answers::expected({}, {},", year, to),
+        );
+
+    match (day::has_part(from, 2), day::has_part(to, 2)) {
+        (true, false) => drop_part_2_test(renamed),
+        (false, true) => add_part_2_test(renamed, year, to),
+        _ => renamed,
+    }
+}
+
+/// Everything about a day that removing or renumbering it touches, checked
+/// up front so nothing is changed if the day can't be moved.
+struct Existing {
+    path: PathBuf,
+    contents: String,
+    input_path: PathBuf,
+    answers: Answers,
+    has_answers: bool,
+}
+
+impl Existing {
+    fn load(year: u32, number: u32, force: bool) -> Result<Self, String> {
+        let path = day_file(year, number);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let registry = std::fs::read_to_string(REGISTRY_FILE).unwrap_or_default();
+        let title = registered_titles(&registry)
+            .remove(&(year, number))
+            .unwrap_or_default();
+
+        let input_path = input::day_input_path(year, number);
+        let has_input = std::fs::metadata(&input_path).is_ok_and(|m| m.len() > 0);
+
+        let answers = load_answers().map_err(|e| e.to_string())?;
+        let has_answers = (1..=2).any(|part| answers.get(year, number, part).is_some());
+
+        if !force {
+            let mut kept = vec![];
+            if !is_untouched(&contents, year, number, &title) {
+                kept.push(format!(
+                    "{} has changed since it was bootstrapped",
+                    path.display()
+                ));
+            }
+            if has_input {
+                kept.push(format!("{} isn't empty", input_path.display()));
+            }
+            if has_answers {
+                kept.push(format!("{} day {} has recorded answers", year, number));
+            }
+            if !kept.is_empty() {
+                return Err(format!(
+                    "{}, use --force to go ahead anyway",
+                    kept.join(", ")
+                ));
+            }
+        }
+
+        Ok(Self {
+            path,
+            contents,
+            input_path,
+            answers,
+            has_answers,
         })
-        .max()
-        .unwrap_or(0)
-        + 1
+    }
+}
+
+/// Deletes `day_N.rs`, its input and its answers, then regenerates the
+/// registry. Refuses to throw away anything but an untouched template and an
+/// empty input unless `force` is set.
+pub fn remove_day(year: u32, number: u32, force: bool, dry_run: bool) -> Result<(), String> {
+    let day = Existing::load(year, number, force)?;
+    let registry = plan_registry(Some(Change::Remove(year, number)))?;
+    let input_exists = day.input_path.exists();
+
+    if dry_run {
+        println!("Would remove {}", day.path.display());
+        if input_exists {
+            println!("Would remove {}", day.input_path.display());
+        }
+        if day.has_answers {
+            println!("Would remove the answers for {} day {}", year, number);
+        }
+        return write_registry(&registry, true);
+    }
+
+    std::fs::remove_file(&day.path)
+        .map_err(|e| format!("Failed to remove {}: {}", day.path.display(), e))?;
+    println!("Removed {}", day.path.display());
+    // Only goes through if that was the last day of the year
+    let _ = std::fs::remove_dir(year_dir(year));
+
+    if input_exists {
+        std::fs::remove_file(&day.input_path)
+            .map_err(|e| format!("Failed to remove {}: {}", day.input_path.display(), e))?;
+        println!("Removed {}", day.input_path.display());
+    }
+
+    if day.has_answers {
+        let mut answers = day.answers;
+        answers.remove_day(year, number);
+        answers.save(&answers_path()).map_err(|e| e.to_string())?;
+        println!("Removed the answers for {} day {}", year, number);
+    }
+
+    write_registry(&registry, false)
+}
+
+/// Moves `day_N.rs`, its input and its answers to another day, updating the
+/// struct name and day number in the file, then regenerates the registry.
+pub fn renumber_day(
+    year: u32,
+    from: u32,
+    to: u32,
+    force: bool,
+    dry_run: bool,
+) -> Result<(), String> {
+    if from == to {
+        return Err(format!("Day {} is already day {}", from, to));
+    }
+    let day = Existing::load(year, from, force)?;
+
+    let to_path = day_file(year, to);
+    let to_input = input::input_path(&input::input_dir(), year, to);
+    if to_path.exists() {
+        return Err(format!("{} already exists", to_path.display()));
+    }
+    if day.input_path.exists() && to_input.exists() {
+        return Err(format!("{} already exists", to_input.display()));
+    }
+    if day.has_answers && (1..=2).any(|part| day.answers.get(year, to, part).is_some()) {
+        return Err(format!("{} day {} already has recorded answers", year, to));
+    }
+
+    let registry = plan_registry(Some(Change::Renumber(year, from, to)))?;
+    let contents = renumber_contents(&day.contents, year, from, to);
+    let input_exists = day.input_path.exists();
+
+    if dry_run {
+        println!("Would move {} to {}", day.path.display(), to_path.display());
+        if input_exists {
+            println!(
+                "Would move {} to {}",
+                day.input_path.display(),
+                to_input.display()
+            );
+        }
+        if day.has_answers {
+            println!(
+                "Would move the answers for {} day {} to day {}",
+                year, from, to
+            );
+        }
+        return write_registry(&registry, true);
+    }
+
+    std::fs::write(&to_path, contents)
+        .map_err(|e| format!("Failed to write {}: {}", to_path.display(), e))?;
+    std::fs::remove_file(&day.path)
+        .map_err(|e| format!("Failed to remove {}: {}", day.path.display(), e))?;
+    println!("Moved {} to {}", day.path.display(), to_path.display());
+
+    if input_exists {
+        if let Some(dir) = to_input.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        std::fs::rename(&day.input_path, &to_input).map_err(|e| {
+            format!(
+                "Failed to move {} to {}: {}",
+                day.input_path.display(),
+                to_input.display(),
+                e
+            )
+        })?;
+        println!(
+            "Moved {} to {}",
+            day.input_path.display(),
+            to_input.display()
+        );
+    }
+
+    if day.has_answers {
+        let mut answers = day.answers;
+        for (part, answer) in answers.remove_day(year, from) {
+            answers.insert(year, to, part, answer);
+        }
+        answers.save(&answers_path()).map_err(|e| e.to_string())?;
+        println!("Moved the answers for {} day {} to day {}", year, from, to);
+    }
+
+    write_registry(&registry, false)
+}
+
+pub fn get_next_highest_day(year: u32) -> Result<u32, String> {
+    let days = days_on_disk()?;
+    let last = days
+        .iter()
+        .filter(|&&(y, _)| y == year)
+        .map(|&(_, d)| d)
+        .max();
+    match last.unwrap_or(0) + 1 {
+        next if next > day::LAST_DAY => Err(format!(
+            "{} already goes up to day {}, pass a day to fill a gap",
+            year,
+            day::LAST_DAY
+        )),
+        next => Ok(next),
+    }
+}
+
+/// The year to bootstrap into when none is given: the one in the environment,
+/// otherwise the latest year on disk.
+pub fn default_year() -> Result<u32, String> {
+    if let Some(year) = day::year_from_env()? {
+        return Ok(year);
+    }
+    let days = days_on_disk()?;
+    Ok(days.last().map(|&(year, _)| year).unwrap_or(LEGACY_YEAR))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(template: &str, number: u32) -> String {
+        render_template(template, 2023, number, "", &[]).unwrap()
+    }
+
+    #[test]
+    fn test_renumber_to_last_day() {
+        for (name, template) in BUILTIN_TEMPLATES {
+            let renumbered = renumber_contents(&rendered(template, 24), 2023, 24, 25);
+            assert_eq!(renumbered, rendered(template, 25), "{}", name);
+            assert!(!renumbered.contains("fn test_part_2()"), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_renumber_from_last_day() {
+        for (name, template) in BUILTIN_TEMPLATES {
+            let renumbered = renumber_contents(&rendered(template, 25), 2023, 25, 3);
+            assert_eq!(renumbered, rendered(template, 3), "{}", name);
+            assert!(
+                renumbered.contains("answers::expected(2023, 3, 2)"),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_renumber_keeps_longer_names() {
+        let contents = "struct Day3;\nstruct Day30;\nimpl Day for Day3 {}\nget_input_for_day!(3);";
+        assert_eq!(
+            renumber_contents(contents, 2023, 3, 4),
+            "struct Day4;\nstruct Day30;\nimpl Day for Day4 {}\nget_input_for_day!(4);"
+        );
+    }
 }
@@ -0,0 +1,926 @@
+use std::{collections::HashMap, fmt::Display, ops::RangeInclusive, time::Duration};
+
+use crate::{
+    answer::Answer,
+    bench::{self, BenchOptions},
+    bootstrap::{self, Example},
+    day,
+    input::InputSource,
+    report::Format,
+    watch,
+};
+
+pub const BIN: &str = "advent_2023";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliError(String);
+
+impl CliError {
+    pub fn new(message: impl Display) -> Self {
+        Self(message.to_string())
+    }
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub struct OptSpec {
+    pub name: &'static str,
+    /// Placeholder for the option's value, `None` for flags
+    pub value: Option<&'static str>,
+    /// Whether the option can be given more than once
+    pub repeat: bool,
+    pub help: &'static str,
+}
+
+pub struct CommandSpec {
+    pub name: &'static str,
+    /// `<REQUIRED>` and `[OPTIONAL]` positional arguments
+    pub args: &'static [&'static str],
+    pub about: &'static str,
+    pub options: &'static [OptSpec],
+}
+
+const INPUT: OptSpec = OptSpec {
+    name: "--input",
+    value: Some("SOURCE"),
+    repeat: false,
+    help: "Input file, directory of day_N.txt files, - for stdin or the input itself if it has more than one line",
+};
+const FORMAT: OptSpec = OptSpec {
+    name: "--format",
+    value: Some("FORMAT"),
+    repeat: false,
+    help: "Output format: text, json or csv (default text)",
+};
+const JOBS: OptSpec = OptSpec {
+    name: "--jobs",
+    value: Some("N"),
+    repeat: false,
+    help: "Number of threads to run parts on (default 1)",
+};
+const TIMEOUT: OptSpec = OptSpec {
+    name: "--timeout",
+    value: Some("SECONDS"),
+    repeat: false,
+    help: "Give up on a part after SECONDS and report it as timed out",
+};
+
+const YEAR: OptSpec = OptSpec {
+    name: "--year",
+    value: Some("YEAR"),
+    repeat: false,
+    help: "Year of the day, $ADVENT_YEAR or the latest one by default",
+};
+
+const DRY_RUN: OptSpec = OptSpec {
+    name: "--dry-run",
+    value: None,
+    repeat: false,
+    help: "Show what would change without writing anything",
+};
+
+const FORCE_DAY: OptSpec = OptSpec {
+    name: "--force",
+    value: None,
+    repeat: false,
+    help: "Go ahead even if the day has been worked on, has an input or has answers",
+};
+
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "run",
+        args: &["<SELECTION>", "[INPUT]"],
+        about: "Run the selected days and parts",
+        options: &[INPUT, FORMAT, JOBS, TIMEOUT],
+    },
+    CommandSpec {
+        name: "all",
+        args: &[],
+        about: "Run every implemented day of every year",
+        options: &[FORMAT, JOBS, TIMEOUT],
+    },
+    CommandSpec {
+        name: "watch",
+        args: &["<SELECTION>", "[INPUT]"],
+        about: "Run the selected days again whenever their input files change",
+        options: &[
+            INPUT,
+            FORMAT,
+            JOBS,
+            TIMEOUT,
+            OptSpec {
+                name: "--interval",
+                value: Some("MS"),
+                repeat: false,
+                help: "How often to check the inputs in milliseconds (default 500)",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "session",
+        args: &["[SELECTION]"],
+        about: "Keep rerunning a day while switching parts and inputs, showing how answers change",
+        options: &[],
+    },
+    CommandSpec {
+        name: "bootstrap",
+        args: &["[DAY]"],
+        about: "Create the files for a new day, the next one by default",
+        options: &[
+            YEAR,
+            OptSpec {
+                name: "--title",
+                value: Some("TITLE"),
+                repeat: false,
+                help: "Puzzle title shown by list",
+            },
+            OptSpec {
+                name: "--template",
+                value: Some("NAME"),
+                repeat: false,
+                help: "Template to start from: basic (default), grid, lines, graph or NAME.rs from the template directory",
+            },
+            OptSpec {
+                name: "--example",
+                value: Some("FILE=P1,P2"),
+                repeat: true,
+                help: "Add tests for an example input (- for stdin) and its answers, leave a part empty to skip it",
+            },
+            DRY_RUN,
+            OptSpec {
+                name: "--sync",
+                value: None,
+                repeat: false,
+                help: "Only regenerate src/registry.rs from the day files",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "unbootstrap",
+        args: &["<DAY>"],
+        about: "Remove a day's file, input and answers",
+        options: &[YEAR, FORCE_DAY, DRY_RUN],
+    },
+    CommandSpec {
+        name: "renumber",
+        args: &["<FROM>", "<TO>"],
+        about: "Move a day's file, input and answers to another day of the same year",
+        options: &[YEAR, FORCE_DAY, DRY_RUN],
+    },
+    CommandSpec {
+        name: "bench",
+        args: &["[SELECTION]"],
+        about: "Benchmark solve times, with parsing and reading the input timed separately for days that can parse on their own",
+        options: &[
+            OptSpec {
+                name: "--runs",
+                value: Some("N"),
+                repeat: false,
+                help: "Measured runs per part (default 20)",
+            },
+            OptSpec {
+                name: "--warmup",
+                value: Some("N"),
+                repeat: false,
+                help: "Unmeasured runs per part (default 3)",
+            },
+            INPUT,
+            OptSpec {
+                name: "--save",
+                value: Some("FILE"),
+                repeat: false,
+                help: "Write the median of each part to FILE",
+            },
+            OptSpec {
+                name: "--baseline",
+                value: Some("FILE"),
+                repeat: false,
+                help: "Compare against medians saved with --save",
+            },
+            OptSpec {
+                name: "--threshold",
+                value: Some("PERCENT"),
+                repeat: false,
+                help: "Slowdown over the baseline that counts as a regression (default 10)",
+            },
+        ],
+    },
+    CommandSpec {
+        name: "record",
+        args: &["<SELECTION>", "[ANSWER]"],
+        about: "Save answers to the answers file, by running the parts if no answer is given",
+        options: &[OptSpec {
+            name: "--force",
+            value: None,
+            repeat: false,
+            help: "Replace answers that were already recorded",
+        }],
+    },
+    CommandSpec {
+        name: "list",
+        args: &[],
+        about: "List the implemented days with their inputs and recorded answers",
+        options: &[],
+    },
+    CommandSpec {
+        name: "help",
+        args: &["[COMMAND]"],
+        about: "Show this help or the help of a command",
+        options: &[],
+    },
+];
+
+const SELECTIONS: &str = "Selections:
+  5:1          Day 5 part 1
+  5 or 5:*     Both parts of day 5
+  1-5:2        Part 2 of days 1 to 5
+  *:1          Part 1 of every implemented day
+  3,7,10-12:1  A comma separated list of any of the above
+  2023:5:1     Any of the above in 2023, without a year it's $ADVENT_YEAR or 2023
+  2023         Every implemented day of 2023
+  *:1-5:*      Days 1 to 5 of every year
+  *            Every implemented day of every year";
+
+pub fn find_command(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|c| c.name == name)
+}
+
+fn usage(spec: &CommandSpec) -> String {
+    let mut usage = format!("{} {}", BIN, spec.name);
+    for arg in spec.args {
+        usage += &format!(" {}", arg);
+    }
+    if !spec.options.is_empty() {
+        usage += " [OPTIONS]";
+    }
+    usage
+}
+
+pub fn help() -> String {
+    let width = COMMANDS.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut help = format!("Usage: {} [COMMAND] [ARGS] [OPTIONS]\n\nCommands:\n", BIN);
+    for command in COMMANDS {
+        help += &format!("  {:width$}  {}\n", command.name, command.about);
+    }
+    help += &format!(
+        "\nWithout a command days are picked from a menu until it is closed, `{} 5:1 [INPUT]` is short for `run`.\n",
+        BIN
+    );
+    help += &format!(
+        "Run `{} help COMMAND` for its options.\n\n{}",
+        BIN, SELECTIONS
+    );
+    help
+}
+
+pub fn command_help(spec: &CommandSpec) -> String {
+    let mut help = format!("Usage: {}\n\n{}\n", usage(spec), spec.about);
+    if !spec.options.is_empty() {
+        let flags = spec
+            .options
+            .iter()
+            .map(|o| match o.value {
+                Some(value) => format!("{} {}", o.name, value),
+                None => o.name.to_string(),
+            })
+            .collect::<Vec<String>>();
+        let width = flags.iter().map(|f| f.len()).max().unwrap_or(0);
+        help += "\nOptions:\n";
+        for (flag, option) in flags.iter().zip(spec.options) {
+            help += &format!("  {:width$}  {}\n", flag, option.help);
+        }
+    }
+    if spec.args.iter().any(|a| a.contains("SELECTION")) {
+        help += &format!("\n{}", SELECTIONS);
+    }
+    help
+}
+
+/// The arguments of a command checked against its [`CommandSpec`].
+struct Matches {
+    positionals: Vec<String>,
+    options: HashMap<&'static str, Vec<String>>,
+}
+
+impl Matches {
+    fn parse(spec: &CommandSpec, args: &[String]) -> Result<Self, CliError> {
+        let mut positionals = vec![];
+        let mut options = HashMap::new();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                positionals.push(arg.clone());
+                continue;
+            }
+
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            let option = spec
+                .options
+                .iter()
+                .find(|o| o.name == name)
+                .ok_or_else(|| {
+                    CliError::new(format!("Unknown option {} for {}", name, spec.name))
+                })?;
+
+            let value = match (option.value, inline_value) {
+                (Some(_), Some(value)) => value,
+                (Some(placeholder), None) => args.next().cloned().ok_or_else(|| {
+                    CliError::new(format!("{} expects a value ({})", name, placeholder))
+                })?,
+                (None, Some(_)) => {
+                    return Err(CliError::new(format!("{} doesn't take a value", name)))
+                }
+                (None, None) => String::new(),
+            };
+            let values = options.entry(option.name).or_insert_with(Vec::new);
+            if !values.is_empty() && !option.repeat {
+                return Err(CliError::new(format!("{} was given more than once", name)));
+            }
+            values.push(value);
+        }
+
+        let required = spec.args.iter().filter(|a| a.starts_with('<')).count();
+        if positionals.len() < required {
+            return Err(CliError::new(format!(
+                "Missing {} for {}",
+                spec.args[positionals.len()],
+                spec.name
+            )));
+        }
+        if positionals.len() > spec.args.len() {
+            return Err(CliError::new(format!(
+                "Unexpected argument {:?} for {}",
+                positionals[spec.args.len()],
+                spec.name
+            )));
+        }
+
+        Ok(Self {
+            positionals,
+            options,
+        })
+    }
+
+    fn positional(&self, index: usize) -> Option<&str> {
+        self.positionals.get(index).map(String::as_str)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values(name).first().map(String::as_str)
+    }
+
+    fn values(&self, name: &str) -> &[String] {
+        self.options
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn parsed<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, CliError> {
+        self.value(name)
+            .map(|v| {
+                v.trim()
+                    .parse()
+                    .map_err(|_| CliError::new(format!("Invalid value {:?} for {}", v, name)))
+            })
+            .transpose()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Years {
+    /// Whichever year is the default when the selection is expanded
+    Default,
+    One(u32),
+    All,
+}
+
+/// One comma separated piece of a [`Selection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SelectionItem {
+    years: Years,
+    /// `None` selects every implemented day
+    days: Option<RangeInclusive<u32>>,
+    /// `None` selects every part the day has
+    part: Option<u32>,
+}
+
+impl SelectionItem {
+    fn parse(s: &str) -> Result<Self, String> {
+        let day_num = |d: &str| {
+            d.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("bad day {:?}", d.trim()))
+        };
+        // Days never go past 25, so four digits up front can only be a year
+        let is_year = |y: &str| y.trim().len() == 4 && y.trim().chars().all(|c| c.is_ascii_digit());
+
+        let fields = s.trim().split(':').collect::<Vec<&str>>();
+        let (years, days, parts) = match fields[..] {
+            [years, days, parts] => (Some(years), days, parts),
+            [years, days] if is_year(years) => (Some(years), days, "*"),
+            // A lone `*` is everything, like the `all` command
+            [years] if is_year(years) || years.trim() == "*" => (Some(years), "*", "*"),
+            [days, parts] => (None, days, parts),
+            [days] => (None, days, "*"),
+            _ => return Err("expected at most YEAR:DAYS:PARTS".to_string()),
+        };
+
+        let years = match years.map(str::trim) {
+            None => Years::Default,
+            Some("*") => Years::All,
+            Some(year) => Years::One(
+                year.parse::<u32>()
+                    .map_err(|_| format!("bad year {:?}", year))?,
+            ),
+        };
+
+        let days = match days.trim() {
+            "*" => None,
+            days => {
+                let (start, end) = match days.split_once('-') {
+                    Some((start, end)) => (day_num(start)?, day_num(end)?),
+                    None => (day_num(days)?, day_num(days)?),
+                };
+                if start == 0 {
+                    return Err("days start at 1".to_string());
+                }
+                if end > day::LAST_DAY {
+                    return Err(format!("days end at {}", day::LAST_DAY));
+                }
+                if start > end {
+                    return Err(format!("the range {} is backwards", days));
+                }
+                Some(start..=end)
+            }
+        };
+
+        let part = match parts.trim() {
+            "*" => None,
+            "1" => Some(1),
+            "2" => Some(2),
+            part => return Err(format!("part {:?} isn't 1, 2 or *", part)),
+        };
+
+        Ok(Self { years, days, part })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection(Vec<SelectionItem>);
+
+impl Selection {
+    /// Every implemented day of every year.
+    pub fn all() -> Self {
+        Self(vec![SelectionItem {
+            years: Years::All,
+            days: None,
+            part: None,
+        }])
+    }
+
+    pub fn parse(s: &str) -> Result<Self, CliError> {
+        s.split(',')
+            .map(SelectionItem::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+            .map_err(|why| {
+                CliError::new(format!(
+                    "Invalid selection {:?}: {}, expected a comma separated list of e.g. 5:1, 5, 1-5:2, * or 2023:5:1",
+                    s, why
+                ))
+            })
+    }
+
+    pub fn is_single(&self) -> bool {
+        match &self.0[..] {
+            [item] => {
+                item.years != Years::All
+                    && item.part.is_some()
+                    && item.days.as_ref().is_some_and(|d| d.start() == d.end())
+            }
+            _ => false,
+        }
+    }
+
+    /// Lists the selected `(year, day, part)` triples in order without
+    /// duplicates, `*` parts only cover the parts each day has. `available` has the implemented `(year, day)` pairs, days
+    /// selected in a single year have to be in it.
+    pub fn expand(
+        &self,
+        available: &[(u32, u32)],
+        default_year: u32,
+    ) -> Result<Vec<(u32, u32, u32)>, CliError> {
+        let mut selected = vec![];
+
+        for item in &self.0 {
+            let years = match item.years {
+                Years::Default => vec![default_year],
+                Years::One(year) => vec![year],
+                Years::All => {
+                    let mut years = available.iter().map(|&(y, _)| y).collect::<Vec<u32>>();
+                    years.dedup();
+                    years
+                }
+            };
+            for year in years {
+                let implemented = available
+                    .iter()
+                    .filter(|&&(y, _)| y == year)
+                    .map(|&(_, d)| d)
+                    .collect::<Vec<u32>>();
+                if implemented.is_empty() {
+                    return Err(CliError::new(format!(
+                        "No days are implemented for {}",
+                        year
+                    )));
+                }
+                let days = match &item.days {
+                    None => implemented.clone(),
+                    // Every year only has the days it has
+                    Some(days) if item.years == Years::All => days
+                        .clone()
+                        .filter(|day| implemented.contains(day))
+                        .collect(),
+                    Some(days) => days.clone().collect(),
+                };
+                for day in days {
+                    if !implemented.contains(&day) {
+                        return Err(CliError::new(format!(
+                            "{} day {} isn't implemented",
+                            year, day
+                        )));
+                    }
+                    // Parts a day doesn't have are only kept when asked for by number
+                    let parts = item
+                        .part
+                        .map(|p| vec![p])
+                        .unwrap_or(day::parts(day).to_vec());
+                    selected.extend(parts.into_iter().map(|part| (year, day, part)));
+                }
+            }
+        }
+
+        selected.sort_unstable();
+        selected.dedup();
+        Ok(selected)
+    }
+}
+
+#[derive(Clone)]
+pub struct RunArgs {
+    pub selection: Selection,
+    pub source: InputSource,
+    pub format: Format,
+    pub jobs: usize,
+    /// Time budget of each part, parts run on the calling thread without one
+    pub timeout: Option<Duration>,
+}
+
+pub enum Command {
+    /// No arguments, pick a day interactively
+    Select,
+    Help(Option<&'static CommandSpec>),
+    Run(RunArgs),
+    Watch(RunArgs, Duration),
+    /// A single day, by default the latest one
+    Session(Option<Selection>),
+    Bootstrap {
+        year: Option<u32>,
+        day: Option<u32>,
+        title: String,
+        template: String,
+        examples: Vec<Example>,
+        dry_run: bool,
+        sync: bool,
+    },
+    Unbootstrap {
+        year: Option<u32>,
+        day: u32,
+        force: bool,
+        dry_run: bool,
+    },
+    Renumber {
+        year: Option<u32>,
+        from: u32,
+        to: u32,
+        force: bool,
+        dry_run: bool,
+    },
+    Bench(Selection, BenchOptions),
+    Record {
+        selection: Selection,
+        answer: Option<Answer>,
+        force: bool,
+    },
+    List,
+}
+
+fn parse_day(day: &str) -> Result<u32, CliError> {
+    match day.parse::<u32>() {
+        Ok(day) if (1..=day::LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(CliError::new(format!("Invalid day {:?}", day))),
+    }
+}
+
+fn run_args(matches: &Matches, selection: Selection) -> Result<RunArgs, CliError> {
+    let source = match (matches.positional(1), matches.value("--input")) {
+        (Some(_), Some(_)) => {
+            return Err(CliError::new(
+                "Give the input either as an argument or with --input, not both",
+            ))
+        }
+        (Some(source), None) | (None, Some(source)) => {
+            InputSource::from_arg(source).map_err(CliError::new)?
+        }
+        (None, None) => InputSource::Default,
+    };
+    let format = match matches.value("--format") {
+        Some(format) => Format::parse(format).ok_or_else(|| {
+            CliError::new(format!(
+                "Invalid format {:?}, expected text, json or csv",
+                format
+            ))
+        })?,
+        None => Format::Text,
+    };
+    let jobs = matches.parsed::<usize>("--jobs")?.unwrap_or(1);
+    if jobs == 0 {
+        return Err(CliError::new("--jobs must be at least 1"));
+    }
+    let timeout = match matches.parsed::<f64>("--timeout")? {
+        Some(secs) if secs.is_finite() && secs > 0.0 => Some(Duration::from_secs_f64(secs)),
+        Some(_) => {
+            return Err(CliError::new(
+                "--timeout must be a positive number of seconds",
+            ))
+        }
+        None => None,
+    };
+
+    Ok(RunArgs {
+        selection,
+        source,
+        format,
+        jobs,
+        timeout,
+    })
+}
+
+/// Parses the arguments after the binary name.
+pub fn parse(args: &[String]) -> Result<Command, CliError> {
+    let Some(first) = args.first() else {
+        return Ok(Command::Select);
+    };
+
+    if matches!(first.as_str(), "--help" | "-h") {
+        return Ok(Command::Help(None));
+    }
+
+    // `advent_2023 5:1 input` and `advent_2023 *` predate the subcommands
+    let (spec, rest) = match find_command(first) {
+        Some(spec) => (spec, &args[1..]),
+        None if first == "*" => (find_command("all").unwrap(), &args[1..]),
+        None if first.starts_with(|c: char| c.is_ascii_digit() || c == '*') => {
+            (find_command("run").unwrap(), args)
+        }
+        None => {
+            return Err(CliError::new(format!(
+                "Unknown command {:?}, run `{} help` for usage",
+                first, BIN
+            )))
+        }
+    };
+
+    if rest.iter().any(|a| a == "--help" || a == "-h") {
+        return Ok(Command::Help(Some(spec)));
+    }
+
+    let matches = Matches::parse(spec, rest)?;
+
+    Ok(match spec.name {
+        "run" => {
+            let selection = Selection::parse(matches.positional(0).unwrap_or_default())?;
+            Command::Run(run_args(&matches, selection)?)
+        }
+        "all" => Command::Run(run_args(&matches, Selection::all())?),
+        "watch" => {
+            let selection = Selection::parse(matches.positional(0).unwrap_or_default())?;
+            let interval = matches
+                .parsed("--interval")?
+                .unwrap_or(watch::DEFAULT_INTERVAL_MS);
+            if interval == 0 {
+                return Err(CliError::new("--interval must be at least 1"));
+            }
+            Command::Watch(
+                run_args(&matches, selection)?,
+                Duration::from_millis(interval),
+            )
+        }
+        "session" => Command::Session(matches.positional(0).map(Selection::parse).transpose()?),
+        "bootstrap" => Command::Bootstrap {
+            year: matches.parsed("--year")?,
+            day: matches.positional(0).map(parse_day).transpose()?,
+            title: matches.value("--title").unwrap_or_default().to_string(),
+            template: matches
+                .value("--template")
+                .unwrap_or(bootstrap::DEFAULT_TEMPLATE)
+                .to_string(),
+            examples: {
+                let examples = matches
+                    .values("--example")
+                    .iter()
+                    .map(|arg| Example::parse(arg).map_err(CliError::new))
+                    .collect::<Result<Vec<Example>, CliError>>()?;
+                if examples.iter().filter(|e| e.path == "-").count() > 1 {
+                    return Err(CliError::new("Only one example can be read from stdin"));
+                }
+                examples
+            },
+            dry_run: matches.flag("--dry-run"),
+            sync: matches.flag("--sync"),
+        },
+        "unbootstrap" => Command::Unbootstrap {
+            year: matches.parsed("--year")?,
+            day: parse_day(matches.positional(0).unwrap_or_default())?,
+            force: matches.flag("--force"),
+            dry_run: matches.flag("--dry-run"),
+        },
+        "renumber" => Command::Renumber {
+            year: matches.parsed("--year")?,
+            from: parse_day(matches.positional(0).unwrap_or_default())?,
+            to: parse_day(matches.positional(1).unwrap_or_default())?,
+            force: matches.flag("--force"),
+            dry_run: matches.flag("--dry-run"),
+        },
+        "bench" => {
+            let selection = matches
+                .positional(0)
+                .map(Selection::parse)
+                .transpose()?
+                .unwrap_or_else(Selection::all);
+            let defaults = BenchOptions::default();
+            let options = BenchOptions {
+                warmup: matches.parsed("--warmup")?.unwrap_or(defaults.warmup),
+                runs: matches.parsed("--runs")?.unwrap_or(defaults.runs),
+                source: matches
+                    .value("--input")
+                    .map(InputSource::from_arg)
+                    .transpose()
+                    .map_err(CliError::new)?
+                    .unwrap_or(defaults.source),
+                save: matches.value("--save").map(str::to_string),
+                baseline: matches.value("--baseline").map(str::to_string),
+                threshold: matches
+                    .parsed("--threshold")?
+                    .unwrap_or(bench::DEFAULT_THRESHOLD),
+            };
+            if options.runs == 0 {
+                return Err(CliError::new("--runs must be at least 1"));
+            }
+            Command::Bench(selection, options)
+        }
+        "record" => {
+            let selection = Selection::parse(matches.positional(0).unwrap_or_default())?;
+            let answer = matches.positional(1).map(Answer::parse);
+            if answer.is_some() && !selection.is_single() {
+                return Err(CliError::new(
+                    "An answer can only be given for a single day and part",
+                ));
+            }
+            Command::Record {
+                selection,
+                answer,
+                force: matches.flag("--force"),
+            }
+        }
+        "list" => Command::List,
+        "help" => match matches.positional(0) {
+            Some(name) => {
+                Command::Help(Some(find_command(name).ok_or_else(|| {
+                    CliError::new(format!("Unknown command {:?}", name))
+                })?))
+            }
+            None => Command::Help(None),
+        },
+        name => unreachable!("command {} has no parser", name),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAILABLE: &[(u32, u32)] = &[(2022, 1), (2022, 2), (2023, 1), (2023, 5), (2023, 25)];
+
+    fn expand(selection: &str) -> Result<Vec<(u32, u32, u32)>, CliError> {
+        Selection::parse(selection)?.expand(AVAILABLE, 2023)
+    }
+
+    #[test]
+    fn test_documented_selections() {
+        assert_eq!(expand("5:1"), Ok(vec![(2023, 5, 1)]));
+        assert_eq!(expand("5"), expand("5:*"));
+        assert_eq!(expand("5:*"), Ok(vec![(2023, 5, 1), (2023, 5, 2)]));
+        assert_eq!(
+            expand("1-5:2"),
+            Err(CliError::new("2023 day 2 isn't implemented"))
+        );
+        assert_eq!(
+            expand("*:1"),
+            Ok(vec![(2023, 1, 1), (2023, 5, 1), (2023, 25, 1)])
+        );
+        assert_eq!(
+            expand("1,5:2,25"),
+            Ok(vec![
+                (2023, 1, 1),
+                (2023, 1, 2),
+                (2023, 5, 2),
+                (2023, 25, 1)
+            ])
+        );
+        assert_eq!(expand("2022:2:1"), Ok(vec![(2022, 2, 1)]));
+        assert_eq!(
+            expand("2022"),
+            Ok(vec![(2022, 1, 1), (2022, 1, 2), (2022, 2, 1), (2022, 2, 2)])
+        );
+        assert_eq!(
+            expand("*:1-5:*"),
+            Ok(vec![
+                (2022, 1, 1),
+                (2022, 1, 2),
+                (2022, 2, 1),
+                (2022, 2, 2),
+                (2023, 1, 1),
+                (2023, 1, 2),
+                (2023, 5, 1),
+                (2023, 5, 2)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_selection_without_year() {
+        let available = [AVAILABLE, &[(2024, 1), (2024, 5)]].concat();
+        let expand = |selection| Selection::parse(selection)?.expand(&available, day::LEGACY_YEAR);
+        assert_eq!(expand("5:1"), Ok(vec![(2023, 5, 1)]));
+        assert_eq!(expand("1"), Ok(vec![(2023, 1, 1), (2023, 1, 2)]));
+        assert_eq!(expand("2024:5:1"), Ok(vec![(2024, 5, 1)]));
+    }
+
+    #[test]
+    fn test_star_is_every_year() {
+        let everything = Selection::all().expand(AVAILABLE, 2023);
+        assert_eq!(expand("*"), everything);
+        assert_eq!(expand("*:*:*"), everything);
+        assert_eq!(expand("5,*"), everything);
+        assert_eq!(
+            expand("*:*"),
+            Ok(vec![
+                (2023, 1, 1),
+                (2023, 1, 2),
+                (2023, 5, 1),
+                (2023, 5, 2),
+                (2023, 25, 1)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_selections() {
+        for selection in ["0", "26", "5-3", "5:3", "1:2:3:4", "x", "*:1-5", "2023:x"] {
+            assert!(
+                Selection::parse(selection).is_err(),
+                "{} should be invalid",
+                selection
+            );
+        }
+        assert_eq!(
+            expand("2021:1"),
+            Err(CliError::new("No days are implemented for 2021"))
+        );
+    }
+
+    #[test]
+    fn test_is_single() {
+        assert!(Selection::parse("5:1").unwrap().is_single());
+        assert!(Selection::parse("2023:5:1").unwrap().is_single());
+        assert!(!Selection::parse("5").unwrap().is_single());
+        assert!(!Selection::parse("*:5:1").unwrap().is_single());
+        assert!(!Selection::parse("5:1,6:1").unwrap().is_single());
+    }
+}
@@ -1,25 +1,125 @@
-pub trait Day {
-    fn part_1(&self, input: &str) -> i64;
+use crate::{error::SolveResult, input::InputError};
 
-    fn part_2(&self, input: &str) -> i64;
+/// Days are shared between threads when running in parallel, so they should
+/// keep any state out of `self`.
+pub trait Day: Send + Sync {
+    fn part_1(&self, input: &str) -> SolveResult;
 
-    fn get_input(&self) -> &'static str;
+    fn part_2(&self, input: &str) -> SolveResult;
+
+    /// Runs only the parsing `part` starts with, so benchmarks can time it
+    /// apart from the rest of the solve. Days without it are timed as a whole.
+    fn parse(&self, _part: u32, _input: &str) -> Option<SolveResult<()>> {
+        None
+    }
+
+    fn get_input(&self) -> Result<String, InputError>;
+}
+
+/// The year this crate started out with. Inputs, answers and selections from
+/// before there were years belong to it.
+pub const LEGACY_YEAR: u32 = 2023;
+
+pub const YEAR_ENV: &str = "ADVENT_YEAR";
+
+/// Every year runs from the 1st to the 25th of December.
+pub const LAST_DAY: u32 = 25;
+
+/// The parts a day has. The last day only has one, its second star comes
+/// for free once every other star has been earned.
+pub fn parts(day: u32) -> &'static [u32] {
+    if day == LAST_DAY {
+        &[1]
+    } else {
+        &[1, 2]
+    }
+}
+
+pub fn has_part(day: u32, part: u32) -> bool {
+    parts(day).contains(&part)
 }
 
+/// The year to use when none is given, if one is set in the environment.
+pub fn year_from_env() -> Result<Option<u32>, String> {
+    match std::env::var(YEAR_ENV) {
+        Ok(year) => year
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("{} should be a year, not {:?}", YEAR_ENV, year)),
+        Err(_) => Ok(None),
+    }
+}
+
+/// A day as listed by [`register_days!`].
+pub struct DayEntry {
+    pub year: u32,
+    pub num: u32,
+    pub title: &'static str,
+    pub new: fn() -> Box<dyn Day>,
+}
+
+/// Declares a module per year with its day modules and builds the `DAYS`
+/// table from entries like `2023 => year_2023 { 1: day_1::Day1 "Trebuchet?!" }`.
 #[macro_export]
-macro_rules! mod_days {
-    ($($day:ident),*) => {
+macro_rules! register_days {
+    ($($year:literal => $year_module:ident {
+        $($num:literal: $module:ident::$day:ident $title:literal),* $(,)?
+    }),* $(,)?) => {
         $(
-            mod $day;
+            mod $year_module {
+                /// Picked up by [`get_input_for_day!`] in the day modules
+                pub const YEAR: u32 = $year;
+
+                $(
+                    pub mod $module;
+                )*
+            }
         )*
+
+        pub static DAYS: &[$crate::day::DayEntry] = &[
+            $($(
+                $crate::day::DayEntry {
+                    year: $year,
+                    num: $num,
+                    title: $title,
+                    new: || Box::new($year_module::$module::$day),
+                },
+            )*)*
+        ];
+
+        #[cfg(feature = "embedded-inputs")]
+        pub static EMBEDDED_INPUTS: &[(u32, u32, &str)] = &[
+            $($(
+                (
+                    $year,
+                    $num,
+                    include_str!(concat!(
+                        "inputs/",
+                        stringify!($year),
+                        "/day_",
+                        stringify!($num),
+                        ".txt"
+                    )),
+                ),
+            )*)*
+        ];
     };
 }
 
+/// Implements `get_input` for a day registered with [`register_days!`], the
+/// year comes from the year module the day lives in.
 #[macro_export]
 macro_rules! get_input_for_day {
     ($day:literal) => {
-        fn get_input(&self) -> &'static str {
-            include_str!(concat!("inputs/day_", stringify!($day), ".txt"))
+        #[cfg(feature = "embedded-inputs")]
+        fn get_input(&self) -> Result<String, $crate::input::InputError> {
+            $crate::input::embedded_input(super::YEAR, $day)
+        }
+
+        #[cfg(not(feature = "embedded-inputs"))]
+        fn get_input(&self) -> Result<String, $crate::input::InputError> {
+            $crate::input::load_day_input(super::YEAR, $day)
         }
     };
 }
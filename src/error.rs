@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+use crate::answer::Answer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    pub part: Option<u32>,
+    /// 1-based line in the puzzle input
+    pub line: Option<usize>,
+    /// 1-based column in the puzzle input
+    pub column: Option<usize>,
+    pub cause: String,
+    /// The part hasn't been written yet, see [`SolveError::unimplemented`]
+    pub unimplemented: bool,
+    // Address and length of the offending slice of the input, resolved by `locate_in`
+    token: Option<(usize, usize)>,
+}
+
+pub type SolveResult<T = Answer> = Result<T, SolveError>;
+
+impl SolveError {
+    pub fn new(cause: impl Display) -> Self {
+        Self {
+            year: None,
+            day: None,
+            part: None,
+            line: None,
+            column: None,
+            cause: cause.to_string(),
+            unimplemented: false,
+            token: None,
+        }
+    }
+
+    /// What a part returns until it's written, so it's reported as to do
+    /// rather than as failing.
+    pub fn unimplemented() -> Self {
+        Self {
+            unimplemented: true,
+            ..Self::new("not implemented yet")
+        }
+    }
+
+    /// `line` and `column` are 0-based, as you'd get them from `enumerate`.
+    pub fn at(line: usize, column: usize, cause: impl Display) -> Self {
+        let mut err = Self::new(cause).on_line(line);
+        err.column = Some(column + 1);
+        err
+    }
+
+    /// Blames `token`, which should be a slice of the puzzle input so the
+    /// runner can work out the line and column with [`SolveError::locate_in`].
+    pub fn at_token(token: &str, cause: impl Display) -> Self {
+        let mut err = Self::new(cause);
+        err.token = Some((token.as_ptr() as usize, token.len()));
+        err
+    }
+
+    /// Sets the 0-based line if the error doesn't have one yet.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line + 1);
+        self
+    }
+
+    pub fn shift_lines(mut self, offset: usize) -> Self {
+        if let Some(line) = self.line.as_mut() {
+            *line += offset;
+        }
+        self
+    }
+
+    pub fn locate_in(mut self, input: &str) -> Self {
+        if let Some((pos, len)) = self.token {
+            let start = input.as_ptr() as usize;
+            if pos >= start && pos + len <= start + input.len() {
+                let before = &input[..pos - start];
+                let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+                self.line = Some(before.matches('\n').count() + 1);
+                self.column = Some(before[line_start..].chars().count() + 1);
+            }
+        }
+        self
+    }
+
+    pub fn in_part(mut self, year: u32, day: u32, part: u32) -> Self {
+        self.year.get_or_insert(year);
+        self.day.get_or_insert(day);
+        self.part.get_or_insert(part);
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(year) = self.year {
+            write!(f, "{} ", year)?;
+        }
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "Day {} Part {} failed", day, part)?,
+            (Some(day), None) => write!(f, "Day {} failed", day)?,
+            _ => write!(f, "Failed")?,
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at line {}, column {}", line, column)?,
+            (Some(line), None) => write!(f, " at line {}", line)?,
+            _ => {}
+        }
+        write!(f, ": {}", self.cause)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Shorthand for `Option::ok_or_else` when the input is missing something.
+pub trait OrMissing<T> {
+    fn or_missing(self, what: &str) -> SolveResult<T>;
+}
+
+impl<T> OrMissing<T> for Option<T> {
+    fn or_missing(self, what: &str) -> SolveResult<T> {
+        self.ok_or_else(|| SolveError::new(format!("missing {}", what)))
+    }
+}
@@ -0,0 +1,149 @@
+use std::{collections::BTreeMap, path::PathBuf, time::Duration};
+
+use crate::report::{RunRecord, Status};
+
+pub const HISTORY_ENV: &str = "ADVENT_HISTORY";
+pub const DEFAULT_HISTORY_FILE: &str = ".advent_history";
+
+const HEADER: &str = "# Last runs against the puzzle inputs, shown by the interactive menu\n";
+
+/// How a part went the last time it ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LastRun {
+    pub status: Status,
+    pub duration: Duration,
+    /// The answer, or the error if the part failed
+    pub detail: String,
+}
+
+/// The last run of every part and the last interactive selection, stored as
+/// `year day part status duration_ns detail` lines. It's only a cache, so
+/// anything that can't be read is dropped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    pub selection: Option<String>,
+    runs: BTreeMap<(u32, u32, u32), LastRun>,
+}
+
+impl History {
+    pub fn parse(contents: &str) -> Self {
+        let mut history = Self::default();
+
+        for line in contents.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            if let Some(selection) = line.strip_prefix("selection ") {
+                history.selection = Some(selection.trim().to_string());
+                continue;
+            }
+
+            let fields = line.splitn(6, ' ').collect::<Vec<&str>>();
+            let [year, day, part, status, duration, detail] = fields[..] else {
+                continue;
+            };
+            let (Ok(year), Ok(day), Ok(part), Some(status), Ok(duration)) = (
+                year.parse(),
+                day.parse(),
+                part.parse(),
+                Status::parse(status),
+                duration.parse(),
+            ) else {
+                continue;
+            };
+            history.runs.insert(
+                (year, day, part),
+                LastRun {
+                    status,
+                    duration: Duration::from_nanos(duration),
+                    detail: unescape(detail),
+                },
+            );
+        }
+
+        history
+    }
+
+    pub fn load() -> Self {
+        std::fs::read_to_string(history_path())
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = history_path();
+        std::fs::write(&path, self.to_string_lines())
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    fn to_string_lines(&self) -> String {
+        let mut out = HEADER.to_string();
+        if let Some(selection) = &self.selection {
+            out += &format!("selection {}\n", selection);
+        }
+        for (&(year, day, part), run) in &self.runs {
+            out += &format!(
+                "{} {} {} {} {} {}\n",
+                year,
+                day,
+                part,
+                run.status,
+                run.duration.as_nanos(),
+                escape(&run.detail)
+            );
+        }
+        out
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&LastRun> {
+        self.runs.get(&(year, day, part))
+    }
+
+    /// Keeps the outcome of a run, parts a day doesn't have aren't worth keeping.
+    pub fn record(&mut self, record: &RunRecord) {
+        if record.status == Status::NotApplicable {
+            return;
+        }
+        let detail = match &record.result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => e.clone(),
+        };
+        self.runs.insert(
+            (record.year, record.day, record.part),
+            LastRun {
+                status: record.status,
+                duration: record.duration,
+                detail,
+            },
+        );
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            (c, _) => out.push(c),
+        }
+    }
+    out
+}
+
+pub fn history_path() -> PathBuf {
+    std::env::var_os(HISTORY_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_HISTORY_FILE))
+}
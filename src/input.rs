@@ -0,0 +1,234 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::day::{Day, LEGACY_YEAR};
+
+pub const INPUT_DIR_ENV: &str = "ADVENT_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "src/inputs";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { year: u32, day: u32, path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { year, day, path } => write!(
+                f,
+                "No input for {} day {} at {} (set {} or pass an input path to use another location)",
+                year,
+                day,
+                path.display(),
+                INPUT_DIR_ENV
+            ),
+            Self::Io { path, source } => {
+                write!(f, "Failed to read input {}: {}", path.display(), source)
+            }
+            Self::Stdin(source) => write!(f, "Failed to read input from stdin: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Where the input for a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Whatever the day provides itself, see [`get_input_for_day`](crate::get_input_for_day).
+    Default,
+    /// A directory containing `YEAR/day_N.txt` or just `day_N.txt` files.
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl InputSource {
+    /// Interprets a command line argument, `-` means stdin, existing paths are
+    /// read from disk and input of more than one line is taken as it is. Any
+    /// other single line must be a path, so a misspelled file name is an error
+    /// rather than solved as the input.
+    pub fn from_arg(arg: &str) -> Result<Self, String> {
+        let multiline = arg.contains('\n');
+        let arg = arg.trim();
+        let path = Path::new(arg);
+        if arg.is_empty() {
+            Ok(Self::Default)
+        } else if arg == "-" {
+            Ok(Self::Stdin)
+        } else if path.is_dir() {
+            Ok(Self::Dir(path.to_path_buf()))
+        } else if path.is_file() {
+            Ok(Self::File(path.to_path_buf()))
+        } else if multiline {
+            Ok(Self::Text(arg.to_string()))
+        } else {
+            Err(format!(
+                "No input file at {}, pipe single line inputs in with -",
+                arg
+            ))
+        }
+    }
+
+    /// Reads stdin right away so the source can be loaded more than once.
+    pub fn buffered(self) -> Result<Self, InputError> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(Self::Text(input))
+            }
+            source => Ok(source),
+        }
+    }
+
+    pub fn load(&self, year: u32, day_num: u32, day: &dyn Day) -> Result<String, InputError> {
+        match self {
+            Self::Default => day.get_input(),
+            Self::Dir(dir) => read_input_or_flat(dir, year, day_num),
+            Self::File(path) => read_file(year, day_num, path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(normalize(&input))
+            }
+            Self::Text(text) => Ok(normalize(text)),
+        }
+    }
+}
+
+/// Inputs span several lines, a single line with a separator or a `.txt` at
+/// the end was meant to be a file.
+pub fn input_dir() -> PathBuf {
+    std::env::var_os(INPUT_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+pub fn input_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day_{}.txt", day))
+}
+
+pub fn read_input(dir: &Path, year: u32, day: u32) -> Result<String, InputError> {
+    read_file(year, day, &input_path(dir, year, day))
+}
+
+/// Like [`read_input`], but also finds `dir/day_N.txt` as it was laid out
+/// before inputs were split by year.
+fn read_input_or_flat(dir: &Path, year: u32, day: u32) -> Result<String, InputError> {
+    let flat = dir.join(format!("day_{}.txt", day));
+    match read_input(dir, year, day) {
+        Err(InputError::Missing { .. }) if flat.is_file() => read_file(year, day, &flat),
+        result => result,
+    }
+}
+
+/// Where the input for a day is read from, `YEAR/day_N.txt` in the input
+/// directory unless an input from before there were years is still around.
+pub fn day_input_path(year: u32, day: u32) -> PathBuf {
+    let dir = input_dir();
+    let path = input_path(&dir, year, day);
+    let flat = dir.join(format!("day_{}.txt", day));
+    if year == LEGACY_YEAR && !path.exists() && flat.is_file() {
+        flat
+    } else {
+        path
+    }
+}
+
+/// Example inputs kept next to the puzzle input as `YEAR/day_N.example*.txt`,
+/// sorted by name.
+pub fn example_paths(year: u32, day: u32) -> Vec<PathBuf> {
+    let prefix = format!("day_{}.example", day);
+    let Ok(entries) = std::fs::read_dir(input_dir().join(year.to_string())) else {
+        return vec![];
+    };
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+        })
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+    paths
+}
+
+#[cfg(not(feature = "embedded-inputs"))]
+pub fn load_day_input(year: u32, day: u32) -> Result<String, InputError> {
+    read_file(year, day, &day_input_path(year, day))
+}
+
+#[cfg(feature = "embedded-inputs")]
+pub fn embedded_input(year: u32, day: u32) -> Result<String, InputError> {
+    crate::EMBEDDED_INPUTS
+        .iter()
+        .find(|&&(y, d, _)| y == year && d == day)
+        .map(|(_, _, input)| normalize(input))
+        .ok_or_else(|| InputError::Missing {
+            year,
+            day,
+            path: input_path(Path::new(DEFAULT_INPUT_DIR), year, day),
+        })
+}
+
+fn read_file(year: u32, day: u32, path: &Path) -> Result<String, InputError> {
+    match std::fs::read_to_string(path) {
+        Ok(input) => Ok(normalize(&input)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::Missing {
+            year,
+            day,
+            path: path.to_path_buf(),
+        }),
+        Err(source) => Err(InputError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+// Some days split on '\n' directly, so a trailing newline would show up as an empty line
+pub fn normalize(input: &str) -> String {
+    input.trim_end_matches(['\n', '\r']).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(""), Ok(InputSource::Default));
+        assert_eq!(InputSource::from_arg("-"), Ok(InputSource::Stdin));
+        assert_eq!(
+            InputSource::from_arg("src"),
+            Ok(InputSource::Dir("src".into()))
+        );
+        assert_eq!(
+            InputSource::from_arg("Cargo.toml"),
+            Ok(InputSource::File("Cargo.toml".into()))
+        );
+        assert_eq!(
+            InputSource::from_arg("1 2\n3 4\n"),
+            Ok(InputSource::Text("1 2\n3 4".to_string()))
+        );
+        assert_eq!(
+            InputSource::from_arg("rn=1,cm-\n"),
+            Ok(InputSource::Text("rn=1,cm-".to_string()))
+        );
+        for missing in ["inptu.txt", "inptu", "inputs/day_5.txt", "rn=1,cm-"] {
+            assert!(InputSource::from_arg(missing).is_err(), "{}", missing);
+        }
+    }
+}
@@ -1,170 +1,510 @@
+mod answer;
+mod answers;
+mod bench;
 mod bootstrap;
+mod cli;
 mod day;
+mod error;
+mod history;
+mod input;
+mod report;
+mod runner;
 mod select;
+mod session;
 mod utils;
+mod watch;
 
-mod_days!(
-    day_1, day_2, day_3, day_4, day_5, day_6, day_7, day_8, day_9, day_10, day_11, day_12, day_13,
-    day_14, day_15, day_16, day_17, day_18, day_19, day_20, day_21, day_22, day_23, day_24
-);
-
-use std::io;
-use std::io::Read;
-
-use day_1::Day1;
-use day_10::Day10;
-use day_11::Day11;
-use day_12::Day12;
-use day_13::Day13;
-use day_14::Day14;
-use day_15::Day15;
-use day_16::Day16;
-use day_17::Day17;
-use day_18::Day18;
-use day_19::Day19;
-use day_2::Day2;
-use day_20::Day20;
-use day_21::Day21;
-use day_22::Day22;
-use day_23::Day23;
-use day_24::Day24;
-use day_3::Day3;
-use day_4::Day4;
-use day_5::Day5;
-use day_6::Day6;
-use day_7::Day7;
-use day_8::Day8;
-use day_9::Day9;
+include!("registry.rs");
 
+use std::{sync::Arc, time::Duration};
+
+use cli::{Command, RunArgs, Selection};
 use day::Day;
-use select::select;
-
-fn match_day(day: u32) -> Box<dyn Day> {
-    match day {
-        1 => Box::new(Day1),
-        2 => Box::new(Day2),
-        3 => Box::new(Day3),
-        4 => Box::new(Day4),
-        5 => Box::new(Day5),
-        6 => Box::new(Day6),
-        7 => Box::new(Day7),
-        8 => Box::new(Day8),
-        9 => Box::new(Day9),
-        10 => Box::new(Day10),
-        11 => Box::new(Day11),
-        12 => Box::new(Day12),
-        13 => Box::new(Day13),
-        14 => Box::new(Day14),
-        15 => Box::new(Day15),
-        16 => Box::new(Day16),
-        17 => Box::new(Day17),
-        18 => Box::new(Day18),
-        19 => Box::new(Day19),
-        20 => Box::new(Day20),
-        21 => Box::new(Day21),
-        22 => Box::new(Day22),
-        23 => Box::new(Day23),
-        24 => Box::new(Day24),
-        _ => panic!("Invalid day selected, exiting..."),
-    }
+use history::History;
+use input::{InputError, InputSource};
+use report::{Reporter, RunRecord, Status, Summary};
+
+fn match_day(year: u32, day: u32) -> Option<Box<dyn Day>> {
+    DAYS.iter()
+        .find(|d| d.year == year && d.num == day)
+        .map(|d| (d.new)())
 }
 
-fn match_part(part: u32, day: Box<dyn Day>, input: &str) -> i64 {
-    match part {
-        1 => day.part_1(input),
-        2 => day.part_2(input),
-        _ => panic!("Invalid part selected, exiting..."),
-    }
+fn get_day(year: u32, day_num: u32) -> Box<dyn Day> {
+    match_day(year, day_num).unwrap_or_else(|| {
+        eprintln!("{} day {} isn't implemented", year, day_num);
+        std::process::exit(2);
+    })
 }
 
-fn run_day(day_num: u32, part: u32, input: &str) {
-    let day = match_day(day_num);
+fn run_day(year: u32, day_num: u32, part: u32, day: &dyn Day, input: &str) -> RunRecord {
+    let start = std::time::Instant::now();
+
+    let result = runner::solve(year, day_num, part, day, input);
+    solved(year, day_num, part, result, start.elapsed())
+}
 
+/// Like [`run_day`], but gives up on the part after `timeout`.
+fn run_day_with_timeout(
+    year: u32,
+    day_num: u32,
+    part: u32,
+    day: &Arc<dyn Day>,
+    input: &str,
+    timeout: Duration,
+) -> RunRecord {
     let start = std::time::Instant::now();
 
-    let result = match_part(part, day, input);
+    match runner::solve_with_timeout(year, day_num, part, day.clone(), input.to_string(), timeout) {
+        Some(result) => solved(year, day_num, part, result, start.elapsed()),
+        None => RunRecord::timed_out(year, day_num, part, timeout),
+    }
+}
 
-    println!(
-        "Day {} Part {} Result: {} (in {}ms)",
-        day_num,
+fn solved(
+    year: u32,
+    day_num: u32,
+    part: u32,
+    result: error::SolveResult,
+    duration: Duration,
+) -> RunRecord {
+    RunRecord {
+        year,
+        day: day_num,
         part,
-        result,
-        start.elapsed().as_millis()
-    );
+        status: match &result {
+            Ok(_) => Status::Ok,
+            Err(e) if e.unimplemented => Status::Todo,
+            Err(_) => Status::Error,
+        },
+        result: result.map_err(|e| e.to_string()),
+        duration,
+        verdict: None,
+    }
+}
+
+fn load_answers() -> answers::Answers {
+    answers::load_answers().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    })
+}
+
+/// The implemented `(year, day)` pairs, sorted.
+fn available_days() -> Vec<(u32, u32)> {
+    let mut days = DAYS.iter().map(|d| (d.year, d.num)).collect::<Vec<_>>();
+    days.sort_unstable();
+    days
+}
+
+/// The year of selections that don't have one: the one in the environment,
+/// otherwise the legacy year, so bootstrapping a newer year doesn't change
+/// what a selection like `5:1` picks.
+fn default_year() -> u32 {
+    let from_env = day::year_from_env().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+    from_env.unwrap_or(day::LEGACY_YEAR)
+}
+
+fn expand(selection: &Selection) -> Vec<(u32, u32, u32)> {
+    selection
+        .expand(&available_days(), default_year())
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        })
+}
+
+/// A selected day with its input, loaded once even when both parts run.
+struct LoadedDay {
+    year: u32,
+    num: u32,
+    day: Arc<dyn Day>,
+    input: Result<String, InputError>,
 }
 
-fn run_all_days() {
+fn run(args: RunArgs) -> bool {
     let start = std::time::Instant::now();
+    let parts = expand(&args.selection);
+    let source = args.source.buffered().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+    // Recorded answers only hold for the puzzle input
+    let answers = (source == InputSource::Default).then(load_answers);
+    let mut reporter = Reporter::new(args.format);
 
-    for day_num in 1..=24 {
-        for part in 1..=2 {
-            let day = match_day(day_num);
+    let mut days: Vec<LoadedDay> = vec![];
+    for &(year, num, _) in &parts {
+        if !days.iter().any(|d| d.year == year && d.num == num) {
+            let day: Arc<dyn Day> = get_day(year, num).into();
+            let input = source.load(year, num, day.as_ref());
+            days.push(LoadedDay {
+                year,
+                num,
+                day,
+                input,
+            });
+        }
+    }
+    let jobs = parts
+        .iter()
+        .map(|&(year, num, part)| {
+            let loaded = days.iter().find(|d| d.year == year && d.num == num);
+            (loaded.unwrap(), part)
+        })
+        .collect::<Vec<_>>();
+
+    let mut records = runner::run_jobs(args.jobs, jobs.len(), |i| {
+        let (loaded, part) = jobs[i];
+        if !day::has_part(loaded.num, part) {
+            return RunRecord::not_applicable(loaded.year, loaded.num, part);
+        }
+        match (&loaded.input, args.timeout) {
+            (Ok(input), Some(timeout)) => {
+                run_day_with_timeout(loaded.year, loaded.num, part, &loaded.day, input, timeout)
+            }
+            (Ok(input), None) => run_day(loaded.year, loaded.num, part, loaded.day.as_ref(), input),
+            (Err(e), _) => RunRecord::skipped(loaded.year, loaded.num, part, e),
+        }
+    });
+
+    let mut summary = Summary {
+        jobs: runner::thread_count(args.jobs, jobs.len()),
+        ..Default::default()
+    };
+    for record in &mut records {
+        if let Some(answers) = &answers {
+            record.verify(answers);
+        }
+        summary.add(record);
+        reporter.record(record);
+    }
 
-            let input = day.get_input();
+    summary.duration = start.elapsed();
+    if parts.len() > 1 {
+        reporter.summary(&summary);
+    }
 
-            run_day(day_num, part, input);
+    // Keep what happened for the interactive menu
+    if source == InputSource::Default {
+        let mut history = History::load();
+        for record in &records {
+            history.record(record);
+        }
+        if let Err(e) = history.save() {
+            eprintln!("{}", e);
         }
     }
 
-    println!("Total time: {}ms", start.elapsed().as_millis());
+    summary.is_ok()
 }
 
-fn main() {
-    let args = std::env::args().collect::<Vec<String>>();
+/// Shows the menu until it's dismissed, running whatever was picked.
+fn interactive() -> bool {
+    let mut ok = true;
 
-    let (date, part) = if let Some(arg) = args.get(1) {
-        match arg.trim() {
-            "help" => {
-                println!("Usage: advent_2023 [day:part] [input]");
-                std::process::exit(0);
+    loop {
+        let mut history = History::load();
+        let answers = load_answers();
+        let choice = match select::select(DAYS, &history, &answers) {
+            Ok(Some(choice)) => choice,
+            Ok(None) => return ok,
+            Err(e) => {
+                eprintln!("{}", e);
+                return false;
             }
-            "bootstrap" => {
-                let num = args
-                    .get(2)
-                    .and_then(|n| n.parse::<u32>().ok())
-                    .unwrap_or_else(bootstrap::get_next_highest_day);
-                bootstrap::bootstrap_file(num);
-                println!("Created day_{}.rs", num);
-                std::process::exit(0);
+        };
+
+        let selection = match Selection::parse(&choice) {
+            Ok(selection) => selection,
+            Err(e) => {
+                eprintln!("{}", e);
+                continue;
             }
-            "*" => {
-                run_all_days();
-                std::process::exit(0);
+        };
+        // A remembered selection can outlive the days it picked
+        if let Err(e) = selection.expand(&available_days(), default_year()) {
+            eprintln!("{}", e);
+            history.selection = None;
+            if let Err(e) = history.save() {
+                eprintln!("{}", e);
             }
-            _ => {
-                let date_part = arg.split(':').collect::<Vec<&str>>();
+            continue;
+        }
 
-                let date = date_part.first().unwrap().parse::<u32>().unwrap();
-                let part = date_part.get(1).unwrap().parse::<u32>().unwrap();
+        history.selection = Some(choice);
+        if let Err(e) = history.save() {
+            eprintln!("{}", e);
+        }
 
-                (date, part)
-            }
+        ok = run(RunArgs {
+            selection,
+            source: InputSource::Default,
+            format: Default::default(),
+            jobs: 1,
+            timeout: None,
+        });
+        println!();
+    }
+}
+
+/// The day a selection picks for a session, with its part unless it picks
+/// all of them.
+fn single_day(selection: &Selection) -> Result<(u32, u32, Option<u32>), String> {
+    let parts = selection
+        .expand(&available_days(), default_year())
+        .map_err(|e| e.to_string())?;
+    let Some(&(year, day, part)) = parts.first() else {
+        return Err("Selection picks no implemented day".to_string());
+    };
+    if parts.iter().any(|&(y, d, _)| (y, d) != (year, day)) {
+        return Err("A session works on a single day".to_string());
+    }
+    let part = (parts.len() < day::parts(day).len()).then_some(part);
+    Ok((year, day, part))
+}
+
+fn session(selection: Option<Selection>) -> bool {
+    let start = match &selection {
+        Some(selection) => single_day(selection),
+        None => {
+            let year = default_year();
+            available_days()
+                .into_iter()
+                .rfind(|&(y, _)| y == year)
+                .map(|(year, day)| (year, day, None))
+                .ok_or_else(|| format!("No days are implemented for {}", year))
+        }
+    };
+    let (year, day, part) = match start {
+        Ok(start) => start,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
         }
-    } else {
-        select()
     };
 
-    let day = match_day(date);
+    session::run(
+        session::Session::new(year, day, part),
+        |s| {
+            Selection::parse(s)
+                .map_err(|e| e.to_string())
+                .and_then(|selection| single_day(&selection))
+        },
+        match_day,
+        run_day,
+    )
+}
 
-    let input = if let Some(arg) = args.get(2) {
-        if arg.trim() != "" {
-            if arg == "-" {
-                let mut input = String::new();
+fn watch(args: RunArgs, interval: Duration) -> bool {
+    let mut days = expand(&args.selection)
+        .into_iter()
+        .map(|(year, day, _)| (year, day))
+        .collect::<Vec<(u32, u32)>>();
+    days.dedup();
+    let paths = watch::watched_paths(&args.source, &days).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
 
-                io::stdin()
-                    .read_to_string(&mut input)
-                    .expect("Failed to read input");
+    watch::watch(&paths, interval, || {
+        run(args.clone());
+    })
+}
 
-                input.trim().to_string()
-            } else {
-                arg.to_string()
+fn bench(selection: Selection, mut options: bench::BenchOptions) -> bool {
+    let parts = expand(&selection);
+    options.source = options.source.buffered().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    });
+
+    bench::run(&parts, get_day, &options)
+}
+
+/// Saves answers to the answers file, either given explicitly or by running
+/// the part(s) against their puzzle input.
+fn record(selection: Selection, given: Option<answer::Answer>, force: bool) -> bool {
+    let path = answers::answers_path();
+    let mut answers = load_answers();
+    let mut ok = true;
+    let mut changed = false;
+
+    for (year, date, part) in expand(&selection) {
+        if !day::has_part(date, part) {
+            println!("{} Day {} has no part {} to record", year, date, part);
+            continue;
+        }
+        let answer = match &given {
+            Some(answer) => answer.clone(),
+            None => {
+                let day = get_day(year, date);
+                let record = match day.get_input() {
+                    Ok(input) => run_day(year, date, part, day.as_ref(), &input),
+                    Err(e) => RunRecord::skipped(year, date, part, e),
+                };
+                match record.result {
+                    Ok(answer) => answer,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        ok = false;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        match answers.get(year, date, part) {
+            Some(existing) if *existing == answer => {
+                println!(
+                    "{} Day {} Part {} already recorded: {}",
+                    year, date, part, answer
+                );
+                continue;
+            }
+            Some(existing) if !force => {
+                eprintln!(
+                    "{} Day {} Part {} is recorded as {}, not {} (use --force to replace it)",
+                    year, date, part, existing, answer
+                );
+                ok = false;
+                continue;
             }
-        } else {
-            day.get_input().to_string()
+            _ => {}
+        }
+
+        println!("Recorded {} Day {} Part {}: {}", year, date, part, answer);
+        answers.insert(year, date, part, answer);
+        changed = true;
+    }
+
+    if changed {
+        if let Err(e) = answers.save(&path) {
+            eprintln!("{}", e);
+            return false;
+        }
+    }
+
+    ok
+}
+
+fn list() -> bool {
+    let answers = load_answers();
+
+    println!("Year  Day  Input    Answers  Title");
+    for (year, day_num) in available_days() {
+        let input = match get_day(year, day_num).get_input() {
+            Ok(_) => "found",
+            Err(InputError::Missing { .. }) => "missing",
+            Err(_) => "error",
+        };
+        let parts = day::parts(day_num);
+        let recorded = parts
+            .iter()
+            .filter(|&&part| answers.get(year, day_num, part).is_some())
+            .count();
+        let title = DAYS
+            .iter()
+            .find(|d| d.year == year && d.num == day_num)
+            .map(|d| d.title);
+        println!(
+            "{}  {:>3}  {:7}  {}/{}      {}",
+            year,
+            day_num,
+            input,
+            recorded,
+            parts.len(),
+            title.unwrap_or_default()
+        );
+    }
+
+    true
+}
+
+fn exit_with(ok: bool) -> ! {
+    std::process::exit(if ok { 0 } else { 1 })
+}
+
+fn main() {
+    runner::install_panic_hook();
+
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+
+    let command = cli::parse(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+
+    let ok = match command {
+        Command::Select => interactive(),
+        Command::Help(None) => {
+            println!("{}", cli::help());
+            true
+        }
+        Command::Help(Some(spec)) => {
+            println!("{}", cli::command_help(spec));
+            true
+        }
+        Command::Run(args) => run(args),
+        Command::Watch(args, interval) => watch(args, interval),
+        Command::Session(selection) => session(selection),
+        Command::Bootstrap {
+            year,
+            day,
+            title,
+            template,
+            examples,
+            dry_run,
+            sync,
+        } => {
+            let result = if sync {
+                bootstrap::sync_registry(dry_run)
+            } else {
+                year.map(Ok)
+                    .unwrap_or_else(bootstrap::default_year)
+                    .and_then(|year| {
+                        let num = day
+                            .map(Ok)
+                            .unwrap_or_else(|| bootstrap::get_next_highest_day(year))?;
+                        bootstrap::bootstrap_file(year, num, &title, &template, &examples, dry_run)
+                    })
+            };
+            result.map_err(|e| eprintln!("{}", e)).is_ok()
         }
-    } else {
-        day.get_input().to_string()
+        Command::Unbootstrap {
+            year,
+            day,
+            force,
+            dry_run,
+        } => year
+            .map(Ok)
+            .unwrap_or_else(bootstrap::default_year)
+            .and_then(|year| bootstrap::remove_day(year, day, force, dry_run))
+            .map_err(|e| eprintln!("{}", e))
+            .is_ok(),
+        Command::Renumber {
+            year,
+            from,
+            to,
+            force,
+            dry_run,
+        } => year
+            .map(Ok)
+            .unwrap_or_else(bootstrap::default_year)
+            .and_then(|year| bootstrap::renumber_day(year, from, to, force, dry_run))
+            .map_err(|e| eprintln!("{}", e))
+            .is_ok(),
+        Command::Bench(selection, options) => bench(selection, options),
+        Command::Record {
+            selection,
+            answer,
+            force,
+        } => record(selection, answer, force),
+        Command::List => list(),
     };
 
-    run_day(date, part, &input);
+    exit_with(ok)
 }
@@ -0,0 +1,30 @@
+// Generated by `advent_2023 bootstrap` from the src/year_*/day_*.rs files.
+// Titles can be edited, they're kept when the file is regenerated.
+register_days! {
+    2023 => year_2023 {
+        1: day_1::Day1 "Trebuchet?!",
+        2: day_2::Day2 "Cube Conundrum",
+        3: day_3::Day3 "Gear Ratios",
+        4: day_4::Day4 "Scratchcards",
+        5: day_5::Day5 "If You Give A Seed A Fertilizer",
+        6: day_6::Day6 "Wait For It",
+        7: day_7::Day7 "Camel Cards",
+        8: day_8::Day8 "Haunted Wasteland",
+        9: day_9::Day9 "Mirage Maintenance",
+        10: day_10::Day10 "Pipe Maze",
+        11: day_11::Day11 "Cosmic Expansion",
+        12: day_12::Day12 "Hot Springs",
+        13: day_13::Day13 "Point of Incidence",
+        14: day_14::Day14 "Parabolic Reflector Dish",
+        15: day_15::Day15 "Lens Library",
+        16: day_16::Day16 "The Floor Will Be Lava",
+        17: day_17::Day17 "Clumsy Crucible",
+        18: day_18::Day18 "Lavaduct Lagoon",
+        19: day_19::Day19 "Aplenty",
+        20: day_20::Day20 "Pulse Propagation",
+        21: day_21::Day21 "Step Counter",
+        22: day_22::Day22 "Sand Slabs",
+        23: day_23::Day23 "A Long Walk",
+        24: day_24::Day24 "Never Tell Me The Odds",
+    },
+}
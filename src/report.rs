@@ -0,0 +1,551 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    answer::Answer,
+    answers::{Answers, Verdict},
+    day,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Error,
+    /// The part never ran, usually because its input couldn't be loaded
+    Skipped,
+    /// The day doesn't have the part, like part 2 of the last day
+    NotApplicable,
+    /// The part ran longer than its time budget
+    Timeout,
+    /// The part returned [`SolveError::unimplemented`](crate::error::SolveError::unimplemented)
+    Todo,
+}
+
+impl Status {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ok" => Some(Self::Ok),
+            "error" => Some(Self::Error),
+            "skipped" => Some(Self::Skipped),
+            "n/a" => Some(Self::NotApplicable),
+            "timeout" => Some(Self::Timeout),
+            "todo" => Some(Self::Todo),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Error => write!(f, "error"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::NotApplicable => write!(f, "n/a"),
+            Self::Timeout => write!(f, "timeout"),
+            Self::Todo => write!(f, "todo"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub result: Result<Answer, String>,
+    pub status: Status,
+    pub duration: Duration,
+    /// Only set when the answer was checked against the answers file
+    pub verdict: Option<Verdict>,
+}
+
+impl RunRecord {
+    pub fn skipped(year: u32, day: u32, part: u32, reason: impl Display) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            result: Err(reason.to_string()),
+            status: Status::Skipped,
+            duration: Duration::ZERO,
+            verdict: None,
+        }
+    }
+
+    pub fn not_applicable(year: u32, day: u32, part: u32) -> Self {
+        Self {
+            status: Status::NotApplicable,
+            ..Self::skipped(year, day, part, format!("Day {} has no part {}", day, part))
+        }
+    }
+
+    pub fn timed_out(year: u32, day: u32, part: u32, timeout: Duration) -> Self {
+        Self {
+            status: Status::Timeout,
+            duration: timeout,
+            ..Self::skipped(
+                year,
+                day,
+                part,
+                format!("Gave up after {}", Nanos(timeout.as_nanos() as f64)),
+            )
+        }
+    }
+
+    pub fn verify(&mut self, answers: &Answers) {
+        if let Ok(answer) = &self.result {
+            self.verdict = Some(answers.verify(self.year, self.day, self.part, answer));
+        }
+    }
+
+    fn expected(&self) -> Option<&Answer> {
+        match &self.verdict {
+            Some(Verdict::Wrong { expected }) => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    pub ok: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub not_applicable: usize,
+    pub timed_out: usize,
+    pub correct: usize,
+    pub wrong: usize,
+    /// Wall-clock time of the whole run
+    pub duration: Duration,
+    /// Time spent in the parts themselves, summed across threads
+    pub cpu: Duration,
+    /// Threads the parts ran on
+    pub jobs: usize,
+}
+
+impl Summary {
+    pub fn add(&mut self, record: &RunRecord) {
+        match record.status {
+            Status::Ok => self.ok += 1,
+            // Parts that aren't written yet don't pass either
+            Status::Error | Status::Todo => self.failed += 1,
+            Status::Skipped => self.skipped += 1,
+            Status::NotApplicable => self.not_applicable += 1,
+            Status::Timeout => self.timed_out += 1,
+        }
+        match record.verdict {
+            Some(Verdict::Correct) => self.correct += 1,
+            Some(Verdict::Wrong { .. }) => self.wrong += 1,
+            _ => {}
+        }
+        self.cpu += record.duration;
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.failed == 0 && self.skipped == 0 && self.timed_out == 0 && self.wrong == 0
+    }
+}
+
+/// Nanoseconds with a unit that keeps a few significant digits.
+pub struct Nanos(pub f64);
+
+impl Display for Nanos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (value, unit) = match self.0 {
+            n if n < 1e3 => (n, "ns"),
+            n if n < 1e6 => (n / 1e3, "µs"),
+            n if n < 1e9 => (n / 1e6, "ms"),
+            n => (n / 1e9, "s"),
+        };
+        write!(f, "{:.2}{}", value, unit)
+    }
+}
+
+/// Prints records as they come in, in whichever format was asked for.
+pub struct Reporter {
+    format: Format,
+    wrote_header: bool,
+    /// Kept for the summary table of text runs
+    records: Vec<RunRecord>,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            wrote_header: false,
+            records: vec![],
+        }
+    }
+
+    fn table_cell(day: u32, part: u32, record: Option<&RunRecord>) -> String {
+        let Some(record) = record else {
+            return if day::has_part(day, part) { "-" } else { "n/a" }.to_string();
+        };
+        match (&record.result, &record.verdict) {
+            (Ok(answer), Some(verdict)) => format!("{} ({})", answer, verdict),
+            (Ok(answer), None) => answer.to_string(),
+            (Err(_), _) => record.status.to_string(),
+        }
+    }
+
+    /// One row per day with both parts next to each other.
+    fn print_table(&self) {
+        let mut days = self
+            .records
+            .iter()
+            .map(|r| (r.year, r.day))
+            .collect::<Vec<(u32, u32)>>();
+        days.dedup();
+
+        let rows = days
+            .into_iter()
+            .map(|(year, day)| {
+                let records = || {
+                    self.records
+                        .iter()
+                        .filter(move |r| r.year == year && r.day == day)
+                };
+                let part = |part| records().find(|r| r.part == part);
+                let time = records().map(|r| r.duration).sum::<Duration>();
+                [
+                    year.to_string(),
+                    day.to_string(),
+                    Self::table_cell(day, 1, part(1)),
+                    Self::table_cell(day, 2, part(2)),
+                    Nanos(time.as_nanos() as f64).to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let header = ["Year", "Day", "Part 1", "Part 2", "Time"];
+        let widths = (0..header.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|r| r[i].chars().count())
+                    .chain([header[i].len()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<usize>>();
+
+        println!();
+        for row in [header.map(String::from)].iter().chain(&rows) {
+            println!(
+                "{:w0$}  {:>w1$}  {:w2$}  {:w3$}  {:>w4$}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                row[4],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+                w3 = widths[3],
+                w4 = widths[4]
+            );
+        }
+        println!();
+    }
+
+    fn csv_header(&mut self) {
+        if !self.wrote_header {
+            // The counts after `expected` are only filled in on the summary row
+            println!("record,year,day,part,status,answer,duration_ns,error,verdict,expected,ok,failed,skipped,not_applicable,timed_out,correct,wrong,cpu_ns,jobs");
+            self.wrote_header = true;
+        }
+    }
+
+    pub fn record(&mut self, record: &RunRecord) {
+        if self.format == Format::Text {
+            self.records.push(record.clone());
+        }
+        match self.format {
+            Format::Text => match &record.result {
+                Ok(answer) => println!("{}", text_result(record, answer)),
+                Err(_) if record.status == Status::NotApplicable => println!(
+                    "{} Day {} Part {} Result: n/a (day {} only has one part)",
+                    record.year, record.day, record.part, record.day
+                ),
+                Err(e) if record.status == Status::Skipped => {
+                    eprintln!(
+                        "{} Day {} Part {} Skipped: {}",
+                        record.year, record.day, record.part, e
+                    )
+                }
+                Err(e) if record.status == Status::Timeout => {
+                    eprintln!(
+                        "{} Day {} Part {} TIMEOUT: {}",
+                        record.year, record.day, record.part, e
+                    )
+                }
+                Err(_) if record.status == Status::Todo => eprintln!(
+                    "{} Day {} Part {} TODO: not implemented yet",
+                    record.year, record.day, record.part
+                ),
+                Err(e) => eprintln!("{}", e),
+            },
+            Format::Json => println!("{}", json_record(record)),
+            Format::Csv => {
+                self.csv_header();
+                println!("{}", csv_record(record));
+            }
+        }
+    }
+
+    pub fn summary(&mut self, summary: &Summary) {
+        let status = if summary.is_ok() {
+            Status::Ok
+        } else {
+            Status::Error
+        };
+        match self.format {
+            Format::Text => {
+                self.print_table();
+                if summary.correct + summary.wrong > 0 {
+                    println!(
+                        "Answers: {} correct, {} wrong",
+                        summary.correct, summary.wrong
+                    );
+                }
+                println!(
+                    "Total time: {} (CPU time: {} across {} thread(s))",
+                    Nanos(summary.duration.as_nanos() as f64),
+                    Nanos(summary.cpu.as_nanos() as f64),
+                    summary.jobs
+                )
+            }
+            Format::Json => println!(
+                "{{\"record\":\"summary\",\"status\":\"{}\",\"ok\":{},\"failed\":{},\"skipped\":{},\"not_applicable\":{},\"timed_out\":{},\"correct\":{},\"wrong\":{},\"duration_ns\":{},\"cpu_ns\":{},\"jobs\":{}}}",
+                status,
+                summary.ok,
+                summary.failed,
+                summary.skipped,
+                summary.not_applicable,
+                summary.timed_out,
+                summary.correct,
+                summary.wrong,
+                summary.duration.as_nanos(),
+                summary.cpu.as_nanos(),
+                summary.jobs
+            ),
+            Format::Csv => {
+                self.csv_header();
+                println!(
+                    "summary,,,,{},,{},,,,{},{},{},{},{},{},{},{},{}",
+                    status,
+                    summary.duration.as_nanos(),
+                    summary.ok,
+                    summary.failed,
+                    summary.skipped,
+                    summary.not_applicable,
+                    summary.timed_out,
+                    summary.correct,
+                    summary.wrong,
+                    summary.cpu.as_nanos(),
+                    summary.jobs
+                );
+            }
+        }
+    }
+}
+
+fn text_result(record: &RunRecord, answer: &Answer) -> String {
+    let verdict = match (&record.verdict, record.expected()) {
+        (_, Some(expected)) => format!(" [wrong, expected {}]", expected),
+        (Some(verdict), None) => format!(" [{}]", verdict),
+        (None, None) => String::new(),
+    };
+    format!(
+        "{} Day {} Part {} Result: {} (in {}){}",
+        record.year,
+        record.day,
+        record.part,
+        answer,
+        Nanos(record.duration.as_nanos() as f64),
+        verdict
+    )
+}
+
+fn json_record(record: &RunRecord) -> String {
+    let (answer, error) = match &record.result {
+        Ok(answer) => (json_answer(answer), "null".to_string()),
+        Err(e) => ("null".to_string(), json_string(e)),
+    };
+    let verdict = record
+        .verdict
+        .as_ref()
+        .map(|v| json_string(&v.to_string()))
+        .unwrap_or_else(|| "null".to_string());
+    let expected = record
+        .expected()
+        .map(json_answer)
+        .unwrap_or_else(|| "null".to_string());
+    format!(
+        "{{\"record\":\"run\",\"year\":{},\"day\":{},\"part\":{},\"status\":\"{}\",\"answer\":{},\"duration_ns\":{},\"error\":{},\"verdict\":{},\"expected\":{}}}",
+        record.year,
+        record.day,
+        record.part,
+        record.status,
+        answer,
+        record.duration.as_nanos(),
+        error,
+        verdict,
+        expected
+    )
+}
+
+fn csv_record(record: &RunRecord) -> String {
+    let (answer, error) = match &record.result {
+        Ok(answer) => (answer.to_string(), String::new()),
+        Err(e) => (String::new(), e.clone()),
+    };
+    let verdict = record
+        .verdict
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or_default();
+    let expected = record.expected().map(|e| e.to_string()).unwrap_or_default();
+    format!(
+        "run,{},{},{},{},{},{},{},{},{},,,,,,,,,",
+        record.year,
+        record.day,
+        record.part,
+        record.status,
+        csv_field(&answer),
+        record.duration.as_nanos(),
+        csv_field(&error),
+        verdict,
+        csv_field(&expected)
+    )
+}
+
+/// Numbers stay numbers, so they can be compared without parsing them back.
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(n) => n.to_string(),
+        Answer::Big(n) => n.to_string(),
+        Answer::Str(s) => json_string(s),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(result: Result<Answer, String>, status: Status) -> RunRecord {
+        RunRecord {
+            year: 2023,
+            day: 5,
+            part: 1,
+            result,
+            status,
+            duration: Duration::from_micros(1500),
+            verdict: None,
+        }
+    }
+
+    #[test]
+    fn test_json_record() {
+        let mut wrong = record(Ok(Answer::Int(-3)), Status::Ok);
+        wrong.verdict = Some(Verdict::Wrong {
+            expected: Answer::Big(u64::MAX as u128 + 1),
+        });
+        assert_eq!(
+            json_record(&wrong),
+            "{\"record\":\"run\",\"year\":2023,\"day\":5,\"part\":1,\"status\":\"ok\",\"answer\":-3,\"duration_ns\":1500000,\"error\":null,\"verdict\":\"wrong\",\"expected\":18446744073709551616}"
+        );
+        assert_eq!(
+            json_record(&record(Ok(Answer::Str("AB\"C".to_string())), Status::Ok)),
+            "{\"record\":\"run\",\"year\":2023,\"day\":5,\"part\":1,\"status\":\"ok\",\"answer\":\"AB\\\"C\",\"duration_ns\":1500000,\"error\":null,\"verdict\":null,\"expected\":null}"
+        );
+        assert_eq!(
+            json_record(&record(Err("bad\ninput".to_string()), Status::Error)),
+            "{\"record\":\"run\",\"year\":2023,\"day\":5,\"part\":1,\"status\":\"error\",\"answer\":null,\"duration_ns\":1500000,\"error\":\"bad\\ninput\",\"verdict\":null,\"expected\":null}"
+        );
+    }
+
+    #[test]
+    fn test_csv_record() {
+        let mut correct = record(Ok(Answer::Int(42)), Status::Ok);
+        correct.verdict = Some(Verdict::Correct);
+        assert_eq!(
+            csv_record(&correct),
+            "run,2023,5,1,ok,42,1500000,,correct,,,,,,,,,,"
+        );
+        assert_eq!(
+            csv_record(&record(Err("a, \"b\"".to_string()), Status::Error)),
+            "run,2023,5,1,error,,1500000,\"a, \"\"b\"\"\",,,,,,,,,,,"
+        );
+    }
+
+    #[test]
+    fn test_text_result() {
+        let mut wrong = record(Ok(Answer::Int(42)), Status::Ok);
+        assert_eq!(
+            text_result(&wrong, &Answer::Int(42)),
+            "2023 Day 5 Part 1 Result: 42 (in 1.50ms)"
+        );
+        wrong.verdict = Some(Verdict::Wrong {
+            expected: Answer::Int(7),
+        });
+        assert_eq!(
+            text_result(&wrong, &Answer::Int(42)),
+            "2023 Day 5 Part 1 Result: 42 (in 1.50ms) [wrong, expected 7]"
+        );
+    }
+
+    #[test]
+    fn test_nanos() {
+        assert_eq!(Nanos(999.0).to_string(), "999.00ns");
+        assert_eq!(Nanos(1_500.0).to_string(), "1.50µs");
+        assert_eq!(Nanos(2_000_000.0).to_string(), "2.00ms");
+        assert_eq!(Nanos(3.25e9).to_string(), "3.25s");
+    }
+}
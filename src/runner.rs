@@ -0,0 +1,257 @@
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    day::Day,
+    error::{SolveError, SolveResult},
+};
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    static IN_SOLVER: Cell<bool> = const { Cell::new(false) };
+    static CANCEL: RefCell<CancelToken> = RefCell::new(CancelToken::default());
+}
+
+/// Recursive solvers can go deeper than the default 2MiB of a spawned thread.
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Tells a part running with a time budget that it's out of time. Solvers
+/// can't be stopped from the outside, so the ones that might run for long
+/// should call [`check_cancelled`] every so often.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Fails once the part running on this thread has run out of time, it never
+/// does for parts without a time budget.
+pub fn check_cancelled() -> SolveResult<()> {
+    if CANCEL.with(|token| token.borrow().is_cancelled()) {
+        Err(SolveError::new("Cancelled after running out of time"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Replaces the default panic output so a panicking solver is reported like
+/// any other [`SolveError`] instead of dumping a backtrace hint. Panics
+/// outside of [`solve`] still go to the previous hook.
+pub fn install_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !IN_SOLVER.with(Cell::get) {
+            previous(info);
+            return;
+        }
+        let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        let message = match info.location() {
+            Some(location) => format!("{} ({}:{})", message, location.file(), location.line()),
+            None => message,
+        };
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+    }));
+}
+
+fn match_part(part: u32, day: &dyn Day, input: &str) -> SolveResult {
+    match part {
+        1 => day.part_1(input),
+        2 => day.part_2(input),
+        _ => Err(SolveError::new(format!("There is no part {}", part))),
+    }
+}
+
+pub fn solve(year: u32, day_num: u32, part: u32, day: &dyn Day, input: &str) -> SolveResult {
+    IN_SOLVER.with(|inside| inside.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match_part(part, day, input)));
+    IN_SOLVER.with(|inside| inside.set(false));
+    result
+        .unwrap_or_else(|_| {
+            let message = LAST_PANIC
+                .with(|last| last.borrow_mut().take())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(SolveError::new(format!("Solver panicked: {}", message)))
+        })
+        .map_err(|e| e.locate_in(input).in_part(year, day_num, part))
+}
+
+/// Like [`solve`], but on a worker thread that's abandoned after `timeout`.
+/// Returns `None` if the part ran out of time, in which case its token is
+/// cancelled and the thread is left to stop on its own.
+pub fn solve_with_timeout(
+    year: u32,
+    day_num: u32,
+    part: u32,
+    day: Arc<dyn Day>,
+    input: String,
+    timeout: Duration,
+) -> Option<SolveResult> {
+    let token = CancelToken::default();
+    let worker_token = token.clone();
+    let (sender, receiver) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .name(format!("{} day {} part {}", year, day_num, part))
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            CANCEL.with(|token| *token.borrow_mut() = worker_token);
+            // Nobody is listening anymore if the part took too long
+            let _ = sender.send(solve(year, day_num, part, day.as_ref(), &input));
+        });
+    if let Err(e) = spawned {
+        return Some(Err(SolveError::new(format!(
+            "Failed to start a thread for the part: {}",
+            e
+        ))
+        .in_part(year, day_num, part)));
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            None
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => Some(Err(SolveError::new(
+            "The part's thread stopped without an answer",
+        )
+        .in_part(year, day_num, part))),
+    }
+}
+
+/// How many threads [`run_jobs`] uses for `count` jobs, there's no point in
+/// more threads than jobs.
+pub fn thread_count(jobs: usize, count: usize) -> usize {
+    if jobs <= 1 || count <= 1 {
+        1
+    } else {
+        jobs.min(count)
+    }
+}
+
+/// Calls `job` for every index in `0..count` on up to `jobs` threads and
+/// returns the results in index order.
+pub fn run_jobs<T: Send>(jobs: usize, count: usize, job: impl Fn(usize) -> T + Sync) -> Vec<T> {
+    let threads = thread_count(jobs, count);
+    if threads == 1 {
+        return (0..count).map(job).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..count).map(|_| None).collect::<Vec<Option<T>>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= count {
+                    break;
+                }
+                let result = job(index);
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every job ran"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Barrier, time::Instant};
+
+    use super::*;
+    use crate::{answer::Answer, input::InputError};
+
+    /// Part 1 spins until it's cancelled, part 2 answers right away.
+    struct Spin(Arc<AtomicBool>);
+
+    impl Day for Spin {
+        fn part_1(&self, _input: &str) -> SolveResult {
+            loop {
+                if let Err(e) = check_cancelled() {
+                    self.0.store(true, Ordering::Relaxed);
+                    return Err(e);
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part_2(&self, _input: &str) -> SolveResult {
+            Ok(2_u64.into())
+        }
+
+        fn get_input(&self) -> Result<String, InputError> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn test_run_jobs_order() {
+        // The first two jobs only get past the barrier if they run at the
+        // same time, and the later ones finish before the earlier ones
+        let barrier = Barrier::new(2);
+        let results = run_jobs(2, 10, |i| {
+            if i < 2 {
+                barrier.wait();
+            }
+            thread::sleep(Duration::from_millis(10 - i as u64));
+            i * 3
+        });
+        assert_eq!(results, (0..10).map(|i| i * 3).collect::<Vec<_>>());
+        assert_eq!(run_jobs(1, 3, |i| i), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let day = Arc::new(Spin(stopped.clone()));
+        let result = solve_with_timeout(2023, 1, 1, day, String::new(), Duration::from_millis(20));
+        assert!(result.is_none());
+
+        // The abandoned part notices it was cancelled and stops
+        let start = Instant::now();
+        while !stopped.load(Ordering::Relaxed) {
+            assert!(
+                start.elapsed() < Duration::from_secs(5),
+                "part never stopped"
+            );
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_within_timeout() {
+        let day = Arc::new(Spin(Arc::default()));
+        let result = solve_with_timeout(2023, 1, 2, day, String::new(), Duration::from_secs(5));
+        assert_eq!(result.unwrap().unwrap(), Answer::from(2_u64));
+        // There's no budget to run out of outside a timed part
+        assert!(check_cancelled().is_ok());
+    }
+}
@@ -1,34 +1,156 @@
-use chrono::{prelude::*, NaiveDate, Weekday};
-use inquire::{DateSelect, Select};
-
-fn select_date() -> u32 {
-    let today = Local::now().date_naive();
-    let date = DateSelect::new("Select Day To Run")
-        .with_starting_date(today)
-        .with_min_date(NaiveDate::from_ymd_opt(2023, 12, 1).unwrap())
-        .with_max_date(NaiveDate::from_ymd_opt(2023, 12, 25).unwrap())
-        .with_week_start(Weekday::Sun)
-        .prompt();
-
-    if let Ok(date) = date {
-        date.day()
-    } else {
-        println!("No date selected, exiting...");
-        std::process::exit(1);
+use std::fmt::Display;
+
+use chrono::prelude::*;
+use inquire::{InquireError, Select};
+
+use crate::{
+    answers::Answers,
+    day::{self, DayEntry},
+    history::History,
+    report::{Nanos, Status},
+};
+
+/// An entry of the interactive menu.
+enum MenuItem {
+    Again(String),
+    All,
+    Day { year: u32, num: u32, label: String },
+    Quit,
+}
+
+impl Display for MenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Again(selection) => write!(f, "Run again: {}", selection),
+            Self::All => write!(f, "All days"),
+            Self::Day { label, .. } => write!(f, "{}", label),
+            Self::Quit => write!(f, "Quit"),
+        }
     }
 }
 
-fn select_part() -> u32 {
-    let part = Select::new("Select Part To Run", vec![1, 2]).prompt();
+/// How a part did last time, like `35 ✓ 1.20ms` or `todo`.
+fn part_status(history: &History, answers: &Answers, year: u32, num: u32, part: u32) -> String {
+    let Some(last) = history.get(year, num, part) else {
+        return "-".to_string();
+    };
+    match last.status {
+        Status::Ok => {
+            let mark = match answers.get(year, num, part) {
+                Some(expected) if expected.to_string() == last.detail => " ✓",
+                Some(_) => " ✗",
+                None => "",
+            };
+            format!(
+                "{}{} {}",
+                last.detail,
+                mark,
+                Nanos(last.duration.as_nanos() as f64)
+            )
+        }
+        Status::Todo => "todo".to_string(),
+        Status::Error => "error".to_string(),
+        Status::Skipped => "no input".to_string(),
+        Status::NotApplicable => "n/a".to_string(),
+        Status::Timeout => "timeout".to_string(),
+    }
+}
+
+fn day_label(entry: &DayEntry, history: &History, answers: &Answers) -> String {
+    let parts = day::parts(entry.num)
+        .iter()
+        .map(|&part| {
+            format!(
+                "P{} {}",
+                part,
+                part_status(history, answers, entry.year, entry.num, part)
+            )
+        })
+        .collect::<Vec<String>>();
+    format!(
+        "{} Day {:>2}  {:<32} {}",
+        entry.year,
+        entry.num,
+        entry.title,
+        parts.join(" | ")
+    )
+}
 
-    if let Ok(part) = part {
-        part
-    } else {
-        println!("No part selected, exiting...");
-        std::process::exit(1);
+/// Asks for the part(s) of a day, `None` goes back to the menu.
+fn select_parts(num: u32) -> Result<Option<&'static str>, String> {
+    if let [_] = day::parts(num) {
+        return Ok(Some("*"));
     }
+    let choices = vec!["Both parts", "Part 1", "Part 2"];
+    match Select::new("Select Part To Run", choices).prompt_skippable() {
+        Ok(Some("Part 1")) => Ok(Some("1")),
+        Ok(Some("Part 2")) => Ok(Some("2")),
+        Ok(Some(_)) => Ok(Some("*")),
+        Ok(None) | Err(InquireError::OperationInterrupted) => Ok(None),
+        Err(e) => Err(menu_error(e)),
+    }
+}
+
+fn menu_error(e: InquireError) -> String {
+    format!("Can't show the menu: {}", e)
 }
 
-pub fn select() -> (u32, u32) {
-    (select_date(), select_part())
+/// Shows the implemented days with how they did last time and asks what to
+/// run next. Returns a selection like `2023:5:*`, or `None` to quit, and an
+/// error when there's no terminal to show the menu on.
+pub fn select(
+    days: &[DayEntry],
+    history: &History,
+    answers: &Answers,
+) -> Result<Option<String>, String> {
+    let mut days = days.iter().collect::<Vec<&DayEntry>>();
+    days.sort_unstable_by_key(|d| (d.year, d.num));
+
+    loop {
+        let mut items = vec![];
+        if let Some(selection) = &history.selection {
+            items.push(MenuItem::Again(selection.clone()));
+        }
+        items.push(MenuItem::All);
+        items.extend(days.iter().map(|entry| MenuItem::Day {
+            year: entry.year,
+            num: entry.num,
+            label: day_label(entry, history, answers),
+        }));
+        items.push(MenuItem::Quit);
+
+        // Without anything to repeat, start on today's puzzle in December
+        let today = Local::now().date_naive();
+        let cursor = if history.selection.is_some() {
+            0
+        } else {
+            items
+                .iter()
+                .position(|item| {
+                    matches!(item, MenuItem::Day { year, num, .. }
+                        if today.month() == 12 && *year == today.year() as u32 && *num == today.day())
+                })
+                .unwrap_or(0)
+        };
+
+        let choice = Select::new("Select Day To Run", items)
+            .with_starting_cursor(cursor)
+            .with_page_size(15)
+            .with_help_message("↑↓ to move, type to filter, enter to run, esc to quit")
+            .prompt_skippable();
+
+        match choice {
+            Ok(Some(MenuItem::Again(selection))) => return Ok(Some(selection)),
+            Ok(Some(MenuItem::All)) => return Ok(Some("*:*:*".to_string())),
+            Ok(Some(MenuItem::Day { year, num, .. })) => {
+                if let Some(parts) = select_parts(num)? {
+                    return Ok(Some(format!("{}:{}:{}", year, num, parts)));
+                }
+            }
+            Ok(Some(MenuItem::Quit) | None) | Err(InquireError::OperationInterrupted) => {
+                return Ok(None)
+            }
+            Err(e) => return Err(menu_error(e)),
+        }
+    }
 }
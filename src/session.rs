@@ -0,0 +1,327 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{self, BufRead},
+    path::PathBuf,
+};
+
+use inquire::{Select, Text};
+
+use crate::{
+    day::{self, Day},
+    history::History,
+    input::{self, InputSource},
+    report::{Format, Reporter, RunRecord, Status},
+};
+
+const COMMANDS: &str = "Commands:
+  enter, run      Run the day again
+  day SELECTION   Switch to another day, like 7, 7:2 or 2022:7
+  part 1|2|*      Switch the part(s) to run
+  real            Use the puzzle input
+  example [N]     Use an example input from YEAR/day_N.example*.txt in the input directory
+  paste           Type or paste an input, ended by a line with just a '.'
+  file PATH       Use the input in PATH
+  help            Show this
+  quit, esc       Leave the session";
+
+/// The input the session runs against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionInput {
+    Real,
+    Example(PathBuf),
+    File(PathBuf),
+    Pasted(String),
+}
+
+impl SessionInput {
+    fn source(&self) -> InputSource {
+        match self {
+            Self::Real => InputSource::Default,
+            Self::Example(path) | Self::File(path) => InputSource::File(path.clone()),
+            Self::Pasted(text) => InputSource::Text(text.clone()),
+        }
+    }
+
+    /// Tells inputs apart when remembering answers, pastes by their contents
+    /// since they all show up as the same "pasted input".
+    fn key(&self) -> String {
+        match self {
+            Self::Pasted(text) => {
+                let mut hasher = DefaultHasher::new();
+                text.hash(&mut hasher);
+                format!("pasted input {:016x}", hasher.finish())
+            }
+            input => input.to_string(),
+        }
+    }
+}
+
+impl Display for SessionInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Real => write!(f, "puzzle input"),
+            Self::Example(path) | Self::File(path) => write!(f, "{}", path.display()),
+            Self::Pasted(_) => write!(f, "pasted input"),
+        }
+    }
+}
+
+/// The day being worked on, with the answers of earlier runs to compare
+/// against. Those of the puzzle input come from the [`History`], so they
+/// outlive the process when it's restarted after a rebuild.
+pub struct Session {
+    year: u32,
+    day: u32,
+    /// `None` runs every part of the day
+    part: Option<u32>,
+    input: SessionInput,
+    previous: HashMap<(u32, u32, u32, String), String>,
+}
+
+impl Session {
+    pub fn new(year: u32, day: u32, part: Option<u32>) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            input: SessionInput::Real,
+            previous: HashMap::new(),
+        }
+    }
+
+    fn prompt(&self) -> String {
+        let part = self
+            .part
+            .map(|part| part.to_string())
+            .unwrap_or_else(|| "*".to_string());
+        format!(
+            "{} Day {} Part {} on {}",
+            self.year, self.day, part, self.input
+        )
+    }
+
+    fn previous_answer(&self, history: &History, part: u32) -> Option<String> {
+        let key = (self.year, self.day, part, self.input.key());
+        if let Some(answer) = self.previous.get(&key) {
+            return Some(answer.clone());
+        }
+        match self.input {
+            SessionInput::Real => history
+                .get(self.year, self.day, part)
+                .filter(|last| last.status == Status::Ok)
+                .map(|last| last.detail.clone()),
+            _ => None,
+        }
+    }
+
+    /// Runs the selected part(s) and says how the answers changed since the
+    /// last run on the same input.
+    fn run(
+        &mut self,
+        get_day: &impl Fn(u32, u32) -> Option<Box<dyn Day>>,
+        run_day: &impl Fn(u32, u32, u32, &dyn Day, &str) -> RunRecord,
+    ) -> bool {
+        let Some(day) = get_day(self.year, self.day) else {
+            eprintln!("{} day {} isn't implemented", self.year, self.day);
+            return false;
+        };
+        let real = self.input == SessionInput::Real;
+        let answers = match real.then(crate::answers::load_answers).transpose() {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}", e);
+                None
+            }
+        };
+        let mut history = History::load();
+        let mut reporter = Reporter::new(Format::Text);
+        let input = self.input.source().load(self.year, self.day, day.as_ref());
+        let mut ok = true;
+
+        for &part in day::parts(self.day) {
+            if self.part.is_some_and(|p| p != part) {
+                continue;
+            }
+            let mut record = match &input {
+                Ok(input) => run_day(self.year, self.day, part, day.as_ref(), input),
+                Err(e) => RunRecord::skipped(self.year, self.day, part, e),
+            };
+            if let Some(answers) = &answers {
+                record.verify(answers);
+            }
+            reporter.record(&record);
+            ok &= record.result.is_ok();
+
+            let Ok(answer) = &record.result else {
+                continue;
+            };
+            let answer = answer.to_string();
+            match self.previous_answer(&history, part) {
+                Some(previous) if previous == answer => println!("  Same as the last run"),
+                Some(previous) => println!("  Changed from {}", previous),
+                None => {}
+            }
+            self.previous
+                .insert((self.year, self.day, part, self.input.key()), answer);
+            if real {
+                history.record(&record);
+            }
+        }
+
+        if real {
+            if let Err(e) = history.save() {
+                eprintln!("{}", e);
+            }
+        }
+        ok
+    }
+
+    fn pick_example(&self, which: Option<&str>) -> Result<PathBuf, String> {
+        let examples = input::example_paths(self.year, self.day);
+        if examples.is_empty() {
+            return Err(format!(
+                "No examples for {} day {}, save them as {}",
+                self.year,
+                self.day,
+                input::input_dir()
+                    .join(self.year.to_string())
+                    .join(format!("day_{}.example.txt", self.day))
+                    .display()
+            ));
+        }
+        match which {
+            Some(n) => n
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|i| examples.get(i).cloned())
+                .ok_or_else(|| format!("Pick an example from 1 to {}", examples.len())),
+            None if examples.len() == 1 => Ok(examples[0].clone()),
+            None => {
+                let names = examples
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<String>>();
+                let name = Select::new("Select Example", names)
+                    .prompt_skippable()
+                    .ok()
+                    .flatten()
+                    .ok_or("No example selected")?;
+                Ok(PathBuf::from(name))
+            }
+        }
+    }
+}
+
+/// `*` for every part of the day, or one it has.
+fn parse_part(day: u32, part: &str) -> Result<Option<u32>, String> {
+    match part {
+        "*" => Ok(None),
+        part => match part.parse::<u32>() {
+            Ok(part) if day::has_part(day, part) => Ok(Some(part)),
+            _ => Err(format!("Day {} has no part {:?}", day, part)),
+        },
+    }
+}
+
+/// Reads lines from stdin until one with just a '.', or the end of it.
+fn read_pasted() -> Result<String, String> {
+    println!("Paste the input, then a line with just a '.'");
+    let mut lines = vec![];
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| format!("Failed to read the input: {}", e))?;
+        if line.trim_end() == "." {
+            break;
+        }
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Keeps asking what to do with the session's day until it's left. `resolve`
+/// turns a selection into a single day and optionally one part of it.
+pub fn run(
+    mut session: Session,
+    resolve: impl Fn(&str) -> Result<(u32, u32, Option<u32>), String>,
+    get_day: impl Fn(u32, u32) -> Option<Box<dyn Day>>,
+    run_day: impl Fn(u32, u32, u32, &dyn Day, &str) -> RunRecord,
+) -> bool {
+    println!("{}\n", COMMANDS);
+    let mut ok = session.run(&get_day, &run_day);
+
+    loop {
+        println!();
+        let line = Text::new(&format!("{} >", session.prompt()))
+            .with_help_message("enter to run again, help for commands, esc to quit")
+            .prompt_skippable();
+        let line = match line {
+            Ok(Some(line)) => line,
+            Ok(None) | Err(_) => return ok,
+        };
+
+        let (command, arg) = match line.trim().split_once(char::is_whitespace) {
+            Some((command, arg)) => (command, Some(arg.trim())),
+            None => (line.trim(), None),
+        };
+        let changed = match (command, arg) {
+            ("" | "run" | "r", None) => Ok(()),
+            ("day" | "d", Some(selection)) => resolve(selection).map(|(year, day, part)| {
+                session.year = year;
+                session.day = day;
+                session.part = part;
+                // Examples belong to the day they were picked for
+                if matches!(session.input, SessionInput::Example(_)) {
+                    session.input = SessionInput::Real;
+                }
+            }),
+            ("part" | "p", Some(part)) => {
+                parse_part(session.day, part).map(|part| session.part = part)
+            }
+            ("real", None) => {
+                session.input = SessionInput::Real;
+                Ok(())
+            }
+            ("example" | "e", which) => session
+                .pick_example(which)
+                .map(|path| session.input = SessionInput::Example(path)),
+            ("paste", None) => read_pasted().map(|text| session.input = SessionInput::Pasted(text)),
+            ("file" | "f", Some(path)) => {
+                let path = PathBuf::from(path);
+                if path.is_file() {
+                    session.input = SessionInput::File(path);
+                    Ok(())
+                } else {
+                    Err(format!("{} isn't a file", path.display()))
+                }
+            }
+            ("help" | "?", None) => {
+                println!("{}", COMMANDS);
+                continue;
+            }
+            ("quit" | "q" | "exit", None) => return ok,
+            _ => Err(format!("Unknown command {:?}, try help", line.trim())),
+        };
+
+        match changed {
+            Ok(()) => ok = session.run(&get_day, &run_day),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pasted_keys() {
+        let first = SessionInput::Pasted("1\n2".to_string());
+        let second = SessionInput::Pasted("1\n3".to_string());
+        assert_ne!(first.key(), second.key());
+        assert_eq!(first.key(), first.clone().key());
+        assert_eq!(first.to_string(), second.to_string());
+        assert_eq!(SessionInput::Real.key(), "puzzle input");
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::parse_lines};
+
+/// Directed edges, parsed from `node: neighbour neighbour ...` lines
+struct Graph {
+    edges: HashMap<String, Vec<String>>,
+}
+
+impl Graph {
+
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        let edges = parse_lines(input, |line| {
+            let (node, neighbours) = line.split_once(':').or_missing("':' after the node")?;
+            Ok((node.trim().to_string(), neighbours.split_whitespace().map(String::from).collect()))
+        })?;
+
+        Ok(Self {
+            edges: edges.into_iter().collect()
+        })
+    }
+
+    pub fn neighbours(&self, node: &str) -> &[String] {
+        self.edges.get(node).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Steps from `start` to every node it can reach
+    #[allow(dead_code)]
+    pub fn distances(&self, start: &str) -> HashMap<String, usize> {
+        let mut distances = HashMap::from([(start.to_string(), 0)]);
+        let mut queue = VecDeque::from([start.to_string()]);
+
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[&node];
+            for next in self.neighbours(&node) {
+                if !distances.contains_key(next) {
+                    distances.insert(next.clone(), distance + 1);
+                    queue.push_back(next.clone());
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+pub struct {{struct}};
+
+impl Day for {{struct}} {
+
+    get_input_for_day!({{day}});
+    fn part_1(&self, input: &str) -> SolveResult {
+        let _graph = Graph::parse(input)?;
+        Err(SolveError::unimplemented())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        let _graph = Graph::parse(input)?;
+        Err(SolveError::unimplemented())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers;
+    {{examples}}
+
+    #[test]
+    fn test_part_1() {
+        let day = {{struct}};
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected({{year}}, {{day}}, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = {{struct}};
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected({{year}}, {{day}}, 2));
+    }
+
+}
\ No newline at end of file
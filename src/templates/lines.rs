@@ -0,0 +1,51 @@
+use crate::{day::Day, error::{SolveError, SolveResult}, get_input_for_day, utils::parse_lines};
+
+struct Line {
+}
+
+impl Line {
+
+    pub fn parse(_line: &str) -> SolveResult<Self> {
+        Err(SolveError::unimplemented())
+    }
+}
+
+pub struct {{struct}};
+
+impl Day for {{struct}} {
+
+    get_input_for_day!({{day}});
+    fn part_1(&self, input: &str) -> SolveResult {
+        let _lines = parse_lines(input, Line::parse)?;
+        Err(SolveError::unimplemented())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        let _lines = parse_lines(input, Line::parse)?;
+        Err(SolveError::unimplemented())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers;
+    {{examples}}
+
+    #[test]
+    fn test_part_1() {
+        let day = {{struct}};
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected({{year}}, {{day}}, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = {{struct}};
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected({{year}}, {{day}}, 2));
+    }
+
+}
\ No newline at end of file
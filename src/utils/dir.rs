@@ -5,10 +5,6 @@ pub trait Movement {
         self.add_to_pos_times(pos, 1)
     }
 
-    fn add_to_pos_unsafe(&self, pos: (isize, isize)) -> (isize, isize) {
-        self.add_to_pos_times_negative(pos, -1)
-    }
-
     fn add_to_pos_times(&self, pos: (usize, usize), times: isize) -> (isize, isize) {
         let pos = (pos.0 as isize, pos.1 as isize);
         let kernel = self.get_kernel();
@@ -80,17 +76,19 @@ impl Direction {
     }
 }
 
-// impl From<ExpandedDirection> for Direction {
-//     fn from(dir: ExpandedDirection) -> Self {
-//         match dir {
-//             ExpandedDirection::North => Self::North,
-//             ExpandedDirection::South => Self::South,
-//             ExpandedDirection::East => Self::East,
-//             ExpandedDirection::West => Self::West,
-//             _ => panic!("Cannot convert {:?} to Cardinal Direction", dir),
-//         }
-//     }
-// }
+impl TryFrom<ExpandedDirection> for Direction {
+    type Error = String;
+
+    fn try_from(dir: ExpandedDirection) -> Result<Self, Self::Error> {
+        match dir {
+            ExpandedDirection::North => Ok(Self::North),
+            ExpandedDirection::South => Ok(Self::South),
+            ExpandedDirection::East => Ok(Self::East),
+            ExpandedDirection::West => Ok(Self::West),
+            _ => Err(format!("{:?} isn't a cardinal direction", dir)),
+        }
+    }
+}
 
 impl Movement for Direction {
     fn get_kernel(&self) -> (isize, isize) {
@@ -103,86 +101,157 @@ impl Movement for Direction {
     }
 }
 
-// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-// pub enum ExpandedDirection {
-//     North,
-//     South,
-//     East,
-//     West,
-//     NorthEast,
-//     NorthWest,
-//     SouthEast,
-//     SouthWest,
-// }
-
-// pub const COMPASS: [ExpandedDirection; 8] = [
-//     ExpandedDirection::North,
-//     ExpandedDirection::South,
-//     ExpandedDirection::East,
-//     ExpandedDirection::West,
-//     ExpandedDirection::NorthEast,
-//     ExpandedDirection::NorthWest,
-//     ExpandedDirection::SouthEast,
-//     ExpandedDirection::SouthWest,
-// ];
-
-// impl ExpandedDirection {
-
-//     pub fn from_cardinals(dirs: (Direction, Option<Direction>)) -> Self {
-//         match dirs.1 {
-//             Some(dir) => {
-//                 match (dirs.0, dir) {
-//                     (Direction::North, Direction::East) => Self::NorthEast,
-//                     (Direction::North, Direction::West) => Self::NorthWest,
-//                     (Direction::South, Direction::East) => Self::SouthEast,
-//                     (Direction::South, Direction::West) => Self::SouthWest,
-//                     (Direction::East, Direction::North) => Self::NorthEast,
-//                     (Direction::East, Direction::South) => Self::SouthEast,
-//                     (Direction::West, Direction::North) => Self::NorthWest,
-//                     (Direction::West, Direction::South) => Self::SouthWest,
-//                     _ => panic!("Invalid cardinal directions: {:?}, {:?}", dirs.0, dir),
-//                 }
-//             },
-//             None => dirs.0.into()
-//         }
-//     }
-
-//     pub fn opposite(&self) -> Self {
-//         match self {
-//             Self::North => Self::South,
-//             Self::South => Self::North,
-//             Self::East => Self::West,
-//             Self::West => Self::East,
-//             Self::NorthEast => Self::SouthWest,
-//             Self::NorthWest => Self::SouthEast,
-//             Self::SouthEast => Self::NorthWest,
-//             Self::SouthWest => Self::NorthEast,
-//         }
-//     }
-// }
-
-// impl From<Direction> for ExpandedDirection {
-//     fn from(dir: Direction) -> Self {
-//         match dir {
-//             Direction::North => Self::North,
-//             Direction::South => Self::South,
-//             Direction::East => Self::East,
-//             Direction::West => Self::West,
-//         }
-//     }
-// }
-
-// impl Movement for ExpandedDirection {
-//     fn get_kernel(&self) -> (isize, isize) {
-//         match self {
-//             Self::North => (0, -1),
-//             Self::South => (0, 1),
-//             Self::East => (1, 0),
-//             Self::West => (-1, 0),
-//             Self::NorthEast => (1, -1),
-//             Self::NorthWest => (-1, -1),
-//             Self::SouthEast => (1, 1),
-//             Self::SouthWest => (-1, 1),
-//         }
-//     }
-// }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ExpandedDirection {
+    North,
+    South,
+    East,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+pub const COMPASS: [ExpandedDirection; 8] = [
+    ExpandedDirection::North,
+    ExpandedDirection::South,
+    ExpandedDirection::East,
+    ExpandedDirection::West,
+    ExpandedDirection::NorthEast,
+    ExpandedDirection::NorthWest,
+    ExpandedDirection::SouthEast,
+    ExpandedDirection::SouthWest,
+];
+
+impl ExpandedDirection {
+    /// Combines a cardinal direction with an optional perpendicular one, two
+    /// directions along the same axis don't make a direction.
+    #[allow(dead_code)]
+    pub fn from_cardinals(dirs: (Direction, Option<Direction>)) -> Option<Self> {
+        match dirs {
+            (dir, None) => Some(dir.into()),
+            (Direction::North, Some(Direction::East))
+            | (Direction::East, Some(Direction::North)) => Some(Self::NorthEast),
+            (Direction::North, Some(Direction::West))
+            | (Direction::West, Some(Direction::North)) => Some(Self::NorthWest),
+            (Direction::South, Some(Direction::East))
+            | (Direction::East, Some(Direction::South)) => Some(Self::SouthEast),
+            (Direction::South, Some(Direction::West))
+            | (Direction::West, Some(Direction::South)) => Some(Self::SouthWest),
+            _ => None,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+            Self::NorthEast => Self::SouthWest,
+            Self::NorthWest => Self::SouthEast,
+            Self::SouthEast => Self::NorthWest,
+            Self::SouthWest => Self::NorthEast,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn is_diagonal(&self) -> bool {
+        !matches!(self, Self::North | Self::South | Self::East | Self::West)
+    }
+}
+
+impl From<Direction> for ExpandedDirection {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::North => Self::North,
+            Direction::South => Self::South,
+            Direction::East => Self::East,
+            Direction::West => Self::West,
+        }
+    }
+}
+
+impl Movement for ExpandedDirection {
+    fn get_kernel(&self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::South => (0, 1),
+            Self::East => (1, 0),
+            Self::West => (-1, 0),
+            Self::NorthEast => (1, -1),
+            Self::NorthWest => (-1, -1),
+            Self::SouthEast => (1, 1),
+            Self::SouthWest => (-1, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernels() {
+        for dir in CARDINALS {
+            let (x, y) = dir.get_kernel();
+            assert_eq!(x.abs() + y.abs(), 1);
+            assert_eq!(dir.opposite().get_kernel(), (-x, -y));
+            assert_eq!(ExpandedDirection::from(dir).get_kernel(), (x, y));
+        }
+        for dir in COMPASS {
+            let (x, y) = dir.get_kernel();
+            assert_eq!(dir.is_diagonal(), x != 0 && y != 0);
+            assert_eq!(dir.opposite().get_kernel(), (-x, -y));
+        }
+        assert_eq!(Direction::North.add_to_pos_times((2, 5), 3), (2, 2));
+        assert_eq!(
+            Direction::West.add_to_pos_times_negative((0, 0), 2),
+            (-2, 0)
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        for dir in CARDINALS {
+            assert_eq!(Direction::try_from(ExpandedDirection::from(dir)), Ok(dir));
+        }
+        for dir in COMPASS.into_iter().filter(ExpandedDirection::is_diagonal) {
+            assert!(Direction::try_from(dir).is_err());
+        }
+    }
+
+    #[test]
+    fn test_from_cardinals() {
+        use Direction::*;
+        assert_eq!(
+            ExpandedDirection::from_cardinals((North, None)),
+            Some(ExpandedDirection::North)
+        );
+        assert_eq!(
+            ExpandedDirection::from_cardinals((North, Some(East))),
+            Some(ExpandedDirection::NorthEast)
+        );
+        assert_eq!(
+            ExpandedDirection::from_cardinals((West, Some(South))),
+            Some(ExpandedDirection::SouthWest)
+        );
+        assert_eq!(ExpandedDirection::from_cardinals((East, Some(West))), None);
+        assert_eq!(
+            ExpandedDirection::from_cardinals((South, Some(South))),
+            None
+        );
+
+        // Every diagonal's kernel is the sum of the two cardinals it's made of
+        for a in CARDINALS {
+            for b in CARDINALS {
+                if let Some(dir) = ExpandedDirection::from_cardinals((a, Some(b))) {
+                    let (ka, kb) = (a.get_kernel(), b.get_kernel());
+                    assert_eq!(dir.get_kernel(), (ka.0 + kb.0, ka.1 + kb.1));
+                }
+            }
+        }
+    }
+}
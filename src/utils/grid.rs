@@ -1,20 +1,45 @@
+use std::fmt::Display;
+
 use super::{
-    dir::{Movement, CARDINALS},
+    dir::{ExpandedDirection, Movement, CARDINALS, COMPASS},
+    render::{Render, ToTile},
     Direction,
 };
+use crate::error::{SolveError, SolveResult};
 
 pub type Position = (usize, usize);
 
+/// A rectangle of tiles stored row after row in a single buffer, so the tile
+/// at `(x, y)` lives at index `y * width + x`.
 pub struct Grid<T> {
     pub width: usize,
     pub height: usize,
-    pub data: Vec<Vec<T>>,
+    data: Vec<T>,
 }
 
 impl<T> Grid<T> {
+    /// Panics unless every row is as wide as the first.
     pub fn new(data: Vec<Vec<T>>) -> Self {
         let width = data[0].len();
         let height = data.len();
+        assert!(
+            data.iter().all(|row| row.len() == width),
+            "every row of a grid should be {} wide",
+            width
+        );
+        Self::from_flat(width, height, data.into_iter().flatten().collect())
+    }
+
+    /// Wraps tiles that are already laid out row after row.
+    pub fn from_flat(width: usize, height: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            data.len(),
+            width * height,
+            "a {}x{} grid needs {} tiles",
+            width,
+            height,
+            width * height
+        );
         Self {
             width,
             height,
@@ -22,19 +47,47 @@ impl<T> Grid<T> {
         }
     }
 
+    #[allow(dead_code)]
     pub fn parse(input: &str) -> Self
     where
         T: From<char>,
     {
-        let data: Vec<Vec<T>> = input
+        Self::new(
+            input
+                .lines()
+                .map(|line| line.chars().map(|c| c.into()).collect())
+                .collect(),
+        )
+    }
+
+    pub fn try_parse(input: &str) -> SolveResult<Self>
+    where
+        T: TryFrom<char>,
+        <T as TryFrom<char>>::Error: Display,
+    {
+        let data = input
             .lines()
-            .map(|line| line.chars().map(|c| c.into()).collect())
-            .collect();
-        Self {
-            width: data[0].len(),
-            height: data.len(),
-            data,
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| T::try_from(c).map_err(|e| SolveError::at(y, x, e)))
+                    .collect::<SolveResult<Vec<T>>>()
+            })
+            .collect::<SolveResult<Vec<Vec<T>>>>()?;
+        let width = data.first().map(|row| row.len()).unwrap_or(0);
+        if width == 0 {
+            return Err(SolveError::new("Grid is empty"));
         }
+        if let Some(y) = data.iter().position(|row| row.len() != width) {
+            return Err(SolveError::new(format!(
+                "Row is {} tiles wide, expected {}",
+                data[y].len(),
+                width
+            ))
+            .on_line(y));
+        }
+        Ok(Self::new(data))
     }
 
     pub fn normalize_position(&self, pos: (isize, isize)) -> Position {
@@ -54,9 +107,18 @@ impl<T> Grid<T> {
         (x as usize, y as usize)
     }
 
-    pub fn get(&self, pos: Position) -> Option<&T> {
+    /// Where the tile at `pos` is in the buffer, if it's in the grid.
+    pub fn index_of(&self, pos: Position) -> Option<usize> {
         let (x, y) = pos;
-        self.data.get(y).and_then(|row| row.get(x))
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.data[i])
     }
 
     pub fn infinite_get(&self, pos: (isize, isize)) -> &T {
@@ -64,42 +126,48 @@ impl<T> Grid<T> {
         self.get(pos).unwrap()
     }
 
-    // pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
-    //     let (x, y) = pos;
-    //     self.data.get_mut(y).and_then(|row| row.get_mut(x))
-    // }
-
-    pub fn iter(&self) -> FullGridIter<T> {
+    pub fn iter(&self) -> FullGridIter<'_, T> {
         FullGridIter {
             grid: self,
-            x: 0,
-            y: 0,
+            index: 0,
         }
     }
 
-    pub fn iter_rev(&self) -> FullGridIter<T> {
+    pub fn iter_rev(&self) -> FullGridIter<'_, T> {
         FullGridIter {
             grid: self,
-            x: self.width - 1,
-            y: self.height - 1,
+            index: self.data.len() - 1,
         }
     }
 
-    pub fn iter_rows(&self) -> GridRowIter<T> {
+    pub fn iter_rows(&self) -> GridRowIter<'_, T> {
         GridRowIter { grid: self, y: 0 }
     }
 
-    pub fn iter_cols(&self) -> GridColIter<T> {
+    pub fn iter_cols(&self) -> GridColIter<'_, T> {
         GridColIter { grid: self, x: 0 }
     }
 
     pub fn get_row(&self, y: usize) -> Option<Vec<&T>> {
-        self.data.get(y).map(|row| row.iter().collect())
+        self.row(y).map(|row| row.iter().collect())
     }
 
-    // pub fn get_col(&self, x: usize) -> Option<Vec<&T>> {
-    //     self.data.iter().map(|row| row.get(x)).collect()
-    // }
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Like [`Grid::iter_rows`] without collecting every row.
+    pub fn rows(&self) -> std::slice::ChunksExact<'_, T> {
+        self.data.chunks_exact(self.width)
+    }
+
+    pub fn col(&self, x: usize) -> Option<ColIter<'_, T>> {
+        (x < self.width).then(|| self.data[x..].iter().step_by(self.width))
+    }
+
+    pub fn get_col(&self, x: usize) -> Option<Vec<&T>> {
+        self.col(x).map(Iterator::collect)
+    }
 
     pub fn size(&self) -> (usize, usize) {
         (self.width, self.height)
@@ -129,94 +197,238 @@ impl<T> Grid<T> {
     }
 
     pub fn get_direct_adjacents(&self, pos: Position) -> Vec<(Position, Direction)> {
-        CARDINALS
-            .iter()
-            .filter_map(|dir| {
-                self.get_next_pos(pos, *dir)
-                    .map(|next_pos| (next_pos, *dir))
-            })
-            .collect()
+        self.neighbours(pos).collect()
     }
 
+    /// The positions next to `pos` that are in the grid, with the direction
+    /// they're in.
+    pub fn neighbours(
+        &self,
+        pos: Position,
+    ) -> impl Iterator<Item = (Position, Direction)> + Clone + '_ {
+        CARDINALS.iter().filter_map(move |dir| {
+            self.get_next_pos(pos, *dir)
+                .map(|next_pos| (next_pos, *dir))
+        })
+    }
+
+    /// Like [`Grid::neighbours`], diagonals included.
+    pub fn moore_neighbours(
+        &self,
+        pos: Position,
+    ) -> impl Iterator<Item = (Position, ExpandedDirection)> + Clone + '_ {
+        COMPASS.iter().filter_map(move |dir| {
+            self.get_next_pos(pos, *dir)
+                .map(|next_pos| (next_pos, *dir))
+        })
+    }
+
+    /// Starts drawing the grid, add overlays before printing it.
+    #[allow(dead_code)]
+    pub fn render(&self) -> Render<'_, T>
+    where
+        T: ToTile,
+    {
+        Render::new(self)
+    }
+
+    /// The four positions next to `pos` on a grid that repeats forever, look
+    /// them up with [`Grid::infinite_get`].
+    #[allow(dead_code)]
     pub fn get_direct_adjacents_wrapping(
         &self,
         pos: (isize, isize),
     ) -> Vec<((isize, isize), Direction)> {
         CARDINALS
             .iter()
-            .map(|dir| {
-                let next_pos = dir.add_to_pos_unsafe((pos.0, pos.1));
-                (next_pos, *dir)
-            })
+            .map(|dir| (dir.add_to_pos_times_negative(pos, 1), *dir))
             .collect()
     }
 
-    // pub fn resize(&mut self, new_width: usize, new_height: usize, default: T)
-    // where
-    //     T: Clone,
-    // {
-    //     if new_width > self.width {
-    //         for row in self.data.iter_mut() {
-    //             row.resize(new_width, default.clone());
-    //         }
-    //     }
-    //     self.data.resize(new_height, vec![default; new_width]);
-    //     self.width = new_width;
-    //     self.height = new_height;
-    // }
+    #[allow(dead_code)]
+    pub fn get_adjacents_with_diag(&self, pos: Position) -> Vec<(Position, ExpandedDirection)> {
+        self.moore_neighbours(pos).collect()
+    }
 
-    // pub fn get_adjacents_with_diag(&self, pos: Position) -> Vec<(Position, ExpandedDirection)> {
-    //     COMPASS
-    //         .iter()
-    //         .filter_map(|dir| {
-    //             self.get_next_pos(pos, *dir)
-    //                 .map(|next_pos| (next_pos, *dir))
-    //         })
-    //         .collect()
-    // }
+    /// The eight positions around `pos` on a grid that repeats forever, look
+    /// them up with [`Grid::infinite_get`].
+    #[allow(dead_code)]
+    pub fn get_adjacents_with_diag_wrapping(
+        &self,
+        pos: (isize, isize),
+    ) -> Vec<((isize, isize), ExpandedDirection)> {
+        COMPASS
+            .iter()
+            .map(|dir| (dir.add_to_pos_times_negative(pos, 1), *dir))
+            .collect()
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.data[i])
+    }
+
+    /// Replaces the tile at `pos`, returning the old one, or `None` if `pos`
+    /// isn't in the grid.
+    #[allow(dead_code)]
+    pub fn set(&mut self, pos: Position, tile: T) -> Option<T> {
+        self.get_mut(pos).map(|old| std::mem::replace(old, tile))
+    }
+
+    /// Panics if either position isn't in the grid.
+    pub fn swap(&mut self, a: Position, b: Position) {
+        let (Some(a), Some(b)) = (self.index_of(a), self.index_of(b)) else {
+            panic!(
+                "can't swap {:?} and {:?} in a {}x{} grid",
+                a, b, self.width, self.height
+            );
+        };
+        self.data.swap(a, b);
+    }
+
+    #[allow(dead_code)]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, tile)| ((i % width, i / width), tile))
+    }
+
+    #[allow(dead_code)]
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        let width = self.width;
+        (y < self.height).then(|| &mut self.data[y * width..(y + 1) * width])
+    }
+
+    pub fn rows_mut(&mut self) -> std::slice::ChunksExactMut<'_, T> {
+        self.data.chunks_exact_mut(self.width)
+    }
+
+    #[allow(dead_code)]
+    pub fn col_mut(&mut self, x: usize) -> Option<ColIterMut<'_, T>> {
+        let width = self.width;
+        (x < width).then(|| self.data[x..].iter_mut().step_by(width))
+    }
+
+    #[allow(dead_code)]
+    pub fn fill(&mut self, tile: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(tile);
+    }
+
+    /// Keeps the tiles that still fit where they were, new ones are `default`.
+    pub fn resize(&mut self, new_width: usize, new_height: usize, default: T)
+    where
+        T: Clone,
+    {
+        let mut old = std::mem::take(&mut self.data)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<Option<T>>>();
+        let width = self.width;
+        let height = self.height;
+        self.data = (0..new_width * new_height)
+            .map(|i| {
+                let (x, y) = (i % new_width, i / new_width);
+                if x < width && y < height {
+                    old[y * width + x].take().unwrap()
+                } else {
+                    default.clone()
+                }
+            })
+            .collect();
+        self.width = new_width;
+        self.height = new_height;
+    }
+
+    /// Rebuilds the grid as `width` by `height`, taking each new position's
+    /// tile from the old position `from` gives for it.
+    fn remap(&mut self, width: usize, height: usize, from: impl Fn(Position) -> Position) {
+        let old_width = self.width;
+        let mut old = std::mem::take(&mut self.data)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<Option<T>>>();
+        self.data = (0..width * height)
+            .map(|i| {
+                let (x, y) = from((i % width, i / width));
+                old[y * old_width + x]
+                    .take()
+                    .expect("every tile is moved once")
+            })
+            .collect();
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Mirrors the grid along the diagonal from the top left, rows become columns.
+    #[allow(dead_code)]
+    pub fn transpose(&mut self) {
+        self.remap(self.height, self.width, |(x, y)| (y, x));
+    }
+
+    /// Turns the grid a quarter clockwise, the left column becomes the top row.
+    pub fn rotate_cw(&mut self) {
+        let height = self.height;
+        self.remap(self.height, self.width, |(x, y)| (y, height - 1 - x));
+    }
+
+    /// Turns the grid a quarter counterclockwise, the top row becomes the left column.
+    #[allow(dead_code)]
+    pub fn rotate_ccw(&mut self) {
+        let width = self.width;
+        self.remap(self.height, self.width, |(x, y)| (width - 1 - y, x));
+    }
+
+    /// Mirrors the grid left to right.
+    #[allow(dead_code)]
+    pub fn flip_horizontal(&mut self) {
+        for row in self.rows_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the grid top to bottom.
+    #[allow(dead_code)]
+    pub fn flip_vertical(&mut self) {
+        let (width, height) = (self.width, self.height);
+        for y in 0..height / 2 {
+            let (top, bottom) = self.data.split_at_mut((height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
 }
 
 pub struct FullGridIter<'a, T> {
     grid: &'a Grid<T>,
-    x: usize,
-    y: usize,
+    index: usize,
 }
 
 impl<'a, T> Iterator for FullGridIter<'a, T> {
     type Item = (Position, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y < self.grid.height {
-            let pos = (self.x, self.y);
-            let item = self.grid.get(pos).unwrap();
-            self.x += 1;
-            if self.x >= self.grid.width {
-                self.x = 0;
-                self.y += 1;
-            }
-            Some((pos, item))
-        } else {
-            None
-        }
+        let item = self.grid.data.get(self.index)?;
+        let pos = self.grid.position_of(self.index);
+        self.index += 1;
+        Some((pos, item))
     }
 }
 
 impl<'a, T> DoubleEndedIterator for FullGridIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.x > 0 {
-            self.x -= 1;
-        } else if self.y > 0 {
-            self.x = self.grid.width - 1;
-            self.y -= 1;
-        } else {
-            return None;
-        }
-        let pos = (self.x, self.y);
-        let item = self.grid.get(pos).unwrap();
-        Some((pos, item))
+        self.index = self.index.checked_sub(1)?;
+        let item = &self.grid.data[self.index];
+        Some((self.grid.position_of(self.index), item))
     }
 }
 
+/// The tiles of a column from top to bottom.
+pub type ColIter<'a, T> = std::iter::StepBy<std::slice::Iter<'a, T>>;
+pub type ColIterMut<'a, T> = std::iter::StepBy<std::slice::IterMut<'a, T>>;
+
 pub struct GridRowIter<'a, T> {
     grid: &'a Grid<T>,
     y: usize,
@@ -226,13 +438,9 @@ impl<'a, T> Iterator for GridRowIter<'a, T> {
     type Item = Vec<&'a T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y < self.grid.height {
-            let row = self.grid.data[self.y].iter().collect();
-            self.y += 1;
-            Some(row)
-        } else {
-            None
-        }
+        let row = self.grid.get_row(self.y)?;
+        self.y += 1;
+        Some(row)
     }
 }
 
@@ -245,13 +453,9 @@ impl<'a, T> Iterator for GridColIter<'a, T> {
     type Item = Vec<&'a T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.x < self.grid.width {
-            let col = self.grid.data.iter().map(|row| &row[self.x]).collect();
-            self.x += 1;
-            Some(col)
-        } else {
-            None
-        }
+        let col = self.grid.get_col(self.x)?;
+        self.x += 1;
+        Some(col)
     }
 }
 
@@ -294,3 +498,118 @@ impl GridPointer {
     //     self.dir = self.dir.ninety_deg(clockwise);
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(tiles: &str) -> Grid<char> {
+        Grid::parse(tiles)
+    }
+
+    fn assert_grid(grid: &Grid<char>, tiles: &str) {
+        assert_eq!(grid.to_string(), tiles);
+        assert_eq!(
+            grid.size(),
+            (grid.rows().next().unwrap().len(), grid.rows().count())
+        );
+        assert_eq!(grid.width * grid.height, grid.iter().count());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let mut tiles = grid("abc\ndef");
+        tiles.transpose();
+        assert_eq!((tiles.width, tiles.height), (2, 3));
+        assert_grid(&tiles, "ad\nbe\ncf");
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut tiles = grid("abc\ndef");
+        tiles.rotate_cw();
+        assert_eq!((tiles.width, tiles.height), (2, 3));
+        assert_grid(&tiles, "da\neb\nfc");
+
+        let mut tiles = grid("abc\ndef");
+        tiles.rotate_ccw();
+        assert_eq!((tiles.width, tiles.height), (2, 3));
+        assert_grid(&tiles, "cf\nbe\nad");
+
+        tiles.rotate_cw();
+        assert_grid(&tiles, "abc\ndef");
+        for _ in 0..4 {
+            tiles.rotate_cw();
+        }
+        assert_grid(&tiles, "abc\ndef");
+    }
+
+    #[test]
+    fn test_flip() {
+        let mut tiles = grid("abc\ndef");
+        tiles.flip_horizontal();
+        assert_grid(&tiles, "cba\nfed");
+        tiles.flip_vertical();
+        assert_grid(&tiles, "fed\ncba");
+
+        let mut tiles = grid("ab\ncd\nef");
+        tiles.flip_vertical();
+        assert_grid(&tiles, "ef\ncd\nab");
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut tiles = grid("abc\ndef");
+        tiles.resize(4, 3, '.');
+        assert_eq!((tiles.width, tiles.height), (4, 3));
+        assert_grid(&tiles, "abc.\ndef.\n....");
+        tiles.resize(2, 1, '.');
+        assert_eq!((tiles.width, tiles.height), (2, 1));
+        assert_grid(&tiles, "ab");
+    }
+
+    #[test]
+    fn test_col_mut() {
+        let mut tiles = grid("abc\ndef");
+        for tile in tiles.col_mut(1).unwrap() {
+            *tile = 'X';
+        }
+        assert_grid(&tiles, "aXc\ndXf");
+        assert!(tiles.col_mut(3).is_none());
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut tiles = grid("abc\ndef");
+        tiles.swap((0, 0), (2, 1));
+        assert_grid(&tiles, "fbc\ndea");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_outside() {
+        grid("abc\ndef").swap((0, 0), (0, 2));
+    }
+
+    #[test]
+    fn test_adjacents_wrapping() {
+        let tiles = grid("abc\ndef");
+        let direct = tiles.get_direct_adjacents_wrapping((0, 0));
+        for (pos, dir) in direct.iter() {
+            assert_eq!(*pos, dir.add_to_pos_times_negative((0, 0), 1));
+        }
+        let mut around = direct
+            .iter()
+            .map(|(pos, _)| *tiles.infinite_get(*pos))
+            .collect::<Vec<_>>();
+        around.sort();
+        assert_eq!(around, ['b', 'c', 'd', 'd']);
+
+        let diag = tiles.get_adjacents_with_diag_wrapping((0, 0));
+        for (pos, dir) in direct.iter() {
+            assert!(diag.contains(&(*pos, ExpandedDirection::from(*dir))));
+        }
+        assert!(diag.contains(&((-1, -1), ExpandedDirection::NorthWest)));
+        assert_eq!(*tiles.infinite_get((-1, -1)), 'f');
+    }
+}
@@ -1,7 +1,11 @@
 pub mod dir;
 pub mod grid;
 pub mod parsing;
+pub mod render;
+pub mod sparse_grid;
 
 pub use dir::Direction;
 pub use grid::Grid;
-pub use parsing::parse_padded_numbers;
+pub use parsing::{parse_lines, parse_number, parse_padded_numbers, parse_sections};
+pub use render::ToTile;
+pub use sparse_grid::SparseGrid;
@@ -1,12 +1,51 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
-pub fn parse_padded_numbers<N>(input: &str) -> Vec<N>
+use crate::error::{SolveError, SolveResult};
+
+pub fn parse_padded_numbers<N>(input: &str) -> SolveResult<Vec<N>>
+where
+    N: FromStr,
+    <N as FromStr>::Err: Display,
+{
+    input.split_whitespace().map(parse_number).collect()
+}
+
+pub fn parse_number<N>(token: &str) -> SolveResult<N>
 where
     N: FromStr,
-    <N as FromStr>::Err: std::fmt::Debug,
+    <N as FromStr>::Err: Display,
 {
+    token
+        .trim()
+        .parse::<N>()
+        .map_err(|e| SolveError::at_token(token, format!("invalid number {:?}: {}", token, e)))
+}
+
+/// Runs `parse` on every line, tagging errors with the line they came from.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> SolveResult<T>,
+) -> SolveResult<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.on_line(i)))
+        .collect()
+}
+
+/// Runs `parse` on each blank line separated section, keeping error lines
+/// relative to the whole input.
+pub fn parse_sections<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> SolveResult<T>,
+) -> SolveResult<Vec<T>> {
+    let mut offset = 0;
     input
-        .split_whitespace()
-        .map(|s| s.parse::<N>().unwrap())
+        .split("\n\n")
+        .map(|section| {
+            let parsed = parse(section).map_err(|e| e.shift_lines(offset));
+            offset += section.lines().count() + 1;
+            parsed
+        })
         .collect()
 }
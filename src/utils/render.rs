@@ -0,0 +1,152 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+};
+
+use super::{grid::Position, Grid};
+
+/// The other way around from the `From<char>`/`TryFrom<char>` the tiles are
+/// parsed with, so a grid can be printed back out.
+pub trait ToTile {
+    fn to_tile(&self) -> char;
+
+    /// What to colour the tile in when colours are on.
+    fn colour(&self) -> Option<Colour> {
+        None
+    }
+}
+
+impl ToTile for char {
+    fn to_tile(&self) -> char {
+        *self
+    }
+}
+
+/// The terminal's basic colours, overlays may use any of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    #[allow(dead_code)]
+    Red,
+    Green,
+    Yellow,
+    #[allow(dead_code)]
+    Blue,
+    #[allow(dead_code)]
+    Magenta,
+    Cyan,
+    Grey,
+}
+
+impl Colour {
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+            Self::Grey => 90,
+        }
+    }
+}
+
+/// Positions drawn differently from their tile.
+struct Overlay {
+    positions: HashSet<Position>,
+    tile: Option<char>,
+    colour: Option<Colour>,
+}
+
+/// Draws a grid a row per line, with overlays on top of the tiles. Later
+/// overlays win where they meet.
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    overlays: Vec<Overlay>,
+    coloured: bool,
+}
+
+impl<'a, T> Render<'a, T>
+where
+    T: ToTile,
+{
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            overlays: vec![],
+            coloured: false,
+        }
+    }
+
+    /// Draws `tile` over every one of `positions`.
+    #[allow(dead_code)]
+    pub fn mark(self, positions: impl IntoIterator<Item = Position>, tile: char) -> Self {
+        self.overlay(positions, Some(tile), None)
+    }
+
+    /// Keeps the tiles of `positions` but colours them in, which only shows
+    /// with colours on.
+    #[allow(dead_code)]
+    pub fn highlight(self, positions: impl IntoIterator<Item = Position>, colour: Colour) -> Self {
+        self.overlay(positions, None, Some(colour))
+    }
+
+    pub fn overlay(
+        mut self,
+        positions: impl IntoIterator<Item = Position>,
+        tile: Option<char>,
+        colour: Option<Colour>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            positions: positions.into_iter().collect(),
+            tile,
+            colour,
+        });
+        self
+    }
+
+    /// Turns the ANSI colours of the tiles and overlays on or off, they're
+    /// off by default.
+    #[allow(dead_code)]
+    pub fn coloured(mut self, coloured: bool) -> Self {
+        self.coloured = coloured;
+        self
+    }
+}
+
+impl<T> Display for Render<'_, T>
+where
+    T: ToTile,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+            for (x, tile) in row.iter().enumerate() {
+                let mut c = tile.to_tile();
+                let mut colour = tile.colour();
+                for overlay in self.overlays.iter() {
+                    if overlay.positions.contains(&(x, y)) {
+                        c = overlay.tile.unwrap_or(c);
+                        colour = overlay.colour.or(colour);
+                    }
+                }
+                match colour.filter(|_| self.coloured) {
+                    Some(colour) => write!(f, "\x1b[{}m{}\x1b[0m", colour.ansi_code(), c)?,
+                    None => f.write_char(c)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: ToTile,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Render::new(self).fmt(f)
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use super::{
+    dir::{ExpandedDirection, Movement, CARDINALS, COMPASS},
+    Direction, Grid, ToTile,
+};
+
+/// A position that can go below zero.
+pub type Point = (isize, isize);
+
+/// Tiles at any signed position, only the ones that were set take up room.
+/// Keeps track of the smallest rectangle around them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    tiles: HashMap<Point, T>,
+    /// Top left and bottom right corners, both inclusive
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            tiles: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pos: Point, tile: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                (min.0.min(pos.0), min.1.min(pos.1)),
+                (max.0.max(pos.0), max.1.max(pos.1)),
+            ),
+            None => (pos, pos),
+        });
+        self.tiles.insert(pos, tile)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.tiles.get(&pos)
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.tiles.contains_key(&pos)
+    }
+
+    /// Every tile that was set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.tiles.iter().map(|(pos, tile)| (*pos, tile))
+    }
+
+    /// The four positions next to `pos`, whether they're set or not.
+    pub fn neighbours(&self, pos: Point) -> impl Iterator<Item = (Point, Direction)> + Clone {
+        CARDINALS
+            .iter()
+            .map(move |dir| (dir.add_to_pos_times_negative(pos, 1), *dir))
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.tiles.get_mut(&pos)
+    }
+
+    /// Shrinks the bounds back around the tiles that are left when an edge
+    /// tile goes.
+    #[allow(dead_code)]
+    pub fn remove(&mut self, pos: Point) -> Option<T> {
+        let tile = self.tiles.remove(&pos)?;
+        if let Some((min, max)) = self.bounds {
+            if pos.0 == min.0 || pos.0 == max.0 || pos.1 == min.1 || pos.1 == max.1 {
+                self.bounds = self.tiles.keys().fold(None, |bounds, p| {
+                    Some(match bounds {
+                        Some((min, max)) => (
+                            (p.0.min(min.0), p.1.min(min.1)),
+                            (p.0.max(max.0), p.1.max(max.1)),
+                        ),
+                        None => (*p, *p),
+                    })
+                });
+            }
+        }
+        Some(tile)
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// The top left and bottom right corners around every tile, both inclusive.
+    #[allow(dead_code)]
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Width and height of the bounds.
+    pub fn size(&self) -> (usize, usize) {
+        self.bounds
+            .map(|(min, max)| ((max.0 - min.0 + 1) as usize, (max.1 - min.1 + 1) as usize))
+            .unwrap_or((0, 0))
+    }
+
+    #[allow(dead_code)]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.tiles.iter_mut().map(|(pos, tile)| (*pos, tile))
+    }
+
+    /// Every tile that was set, row by row from the top left.
+    #[allow(dead_code)]
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (Point, &T)> {
+        let mut tiles = self.iter().collect::<Vec<_>>();
+        tiles.sort_unstable_by_key(|((x, y), _)| (*y, *x));
+        tiles.into_iter()
+    }
+
+    #[allow(dead_code)]
+    pub fn get_next_pos(&self, pos: Point, dir: impl Movement) -> Point {
+        self.get_next_pos_times(pos, dir, 1)
+    }
+
+    pub fn get_next_pos_times(&self, pos: Point, dir: impl Movement, times: usize) -> Point {
+        dir.add_to_pos_times_negative(pos, times as isize)
+    }
+
+    /// Like [`SparseGrid::neighbours`], diagonals included.
+    #[allow(dead_code)]
+    pub fn moore_neighbours(
+        &self,
+        pos: Point,
+    ) -> impl Iterator<Item = (Point, ExpandedDirection)> + Clone {
+        COMPASS
+            .iter()
+            .map(move |dir| (dir.add_to_pos_times_negative(pos, 1), *dir))
+    }
+
+    /// Fills the bounds into a [`Grid`], with `empty` where nothing was set.
+    /// The grid's `(0, 0)` is the top left corner of the bounds.
+    #[allow(dead_code)]
+    pub fn to_grid(&self, empty: T) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        let (min, _) = self.bounds?;
+        let (width, height) = self.size();
+        let tiles = (0..width * height)
+            .map(|i| {
+                let pos = (min.0 + (i % width) as isize, min.1 + (i / width) as isize);
+                self.get(pos).unwrap_or(&empty).clone()
+            })
+            .collect();
+        Some(Grid::from_flat(width, height, tiles))
+    }
+
+    /// Draws the bounds a row per line, with `empty` where nothing was set.
+    #[allow(dead_code)]
+    pub fn render(&self, empty: char) -> String
+    where
+        T: ToTile,
+    {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        (min.1..=max.1)
+            .map(|y| {
+                (min.0..=max.0)
+                    .map(|x| self.get((x, y)).map_or(empty, ToTile::to_tile))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, tile) in iter {
+            grid.insert(pos, tile);
+        }
+        grid
+    }
+}
+
+impl<T> From<&Grid<T>> for SparseGrid<T>
+where
+    T: Clone,
+{
+    fn from(grid: &Grid<T>) -> Self {
+        grid.iter()
+            .map(|((x, y), tile)| ((x as isize, y as isize), tile.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sparse(tiles: &[(Point, char)]) -> SparseGrid<char> {
+        tiles.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut tiles = SparseGrid::new();
+        assert_eq!((tiles.bounds(), tiles.size()), (None, (0, 0)));
+        tiles.insert((2, -1), 'a');
+        assert_eq!(tiles.bounds(), Some(((2, -1), (2, -1))));
+        tiles.insert((-3, 4), 'b');
+        tiles.insert((0, 0), 'c');
+        assert_eq!(tiles.bounds(), Some(((-3, -1), (2, 4))));
+        assert_eq!(tiles.size(), (6, 6));
+        assert_eq!(tiles.len(), 3);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tiles = sparse(&[((-2, 0), 'a'), ((0, 0), 'b'), ((1, 3), 'c')]);
+        assert_eq!(tiles.remove((5, 5)), None);
+        assert_eq!(tiles.remove((0, 0)), Some('b'));
+        assert_eq!(tiles.bounds(), Some(((-2, 0), (1, 3))));
+        assert_eq!(tiles.remove((1, 3)), Some('c'));
+        assert_eq!(tiles.bounds(), Some(((-2, 0), (-2, 0))));
+        assert_eq!(tiles.remove((-2, 0)), Some('a'));
+        assert_eq!(tiles.bounds(), None);
+        assert!(tiles.is_empty());
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let tiles = sparse(&[((-1, -1), 'a'), ((1, 0), 'b')]);
+        let grid = tiles.to_grid('.').unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.to_string(), "a..\n..b");
+        assert!(SparseGrid::<char>::new().to_grid('.').is_none());
+    }
+
+    #[test]
+    fn test_render() {
+        let tiles = sparse(&[((0, -2), '#'), ((-2, 0), '#'), ((0, 0), 'S')]);
+        assert_eq!(tiles.render('.'), "..#\n...\n#.S");
+        assert_eq!(SparseGrid::<char>::new().render('.'), "");
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::<char>::parse("ab\ncd");
+        let tiles = SparseGrid::from(&grid);
+        assert_eq!(tiles.get((1, 1)), Some(&'d'));
+        assert_eq!(tiles.to_grid(' ').unwrap().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let tiles = SparseGrid::<char>::new();
+        let mut cardinal = tiles.neighbours((0, 0)).map(|(p, _)| p).collect::<Vec<_>>();
+        cardinal.sort();
+        assert_eq!(cardinal, [(-1, 0), (0, -1), (0, 1), (1, 0)]);
+        let moore = tiles
+            .moore_neighbours((0, 0))
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+        assert_eq!(moore.len(), 8);
+        assert!(moore
+            .iter()
+            .all(|p| p.0.abs() <= 1 && p.1.abs() <= 1 && *p != (0, 0)));
+        assert_eq!(tiles.get_next_pos_times((0, 0), Direction::North, 3), (0, -3));
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::input::{self, InputSource};
+
+pub const DEFAULT_INTERVAL_MS: u64 = 500;
+
+/// The files the selected days read their input from.
+pub fn watched_paths(source: &InputSource, days: &[(u32, u32)]) -> Result<Vec<PathBuf>, String> {
+    let mut paths = match source {
+        #[cfg(feature = "embedded-inputs")]
+        InputSource::Default => return Err(
+            "The puzzle inputs are baked into this build, give an input file or directory to watch"
+                .to_string(),
+        ),
+        #[cfg(not(feature = "embedded-inputs"))]
+        InputSource::Default => days
+            .iter()
+            .map(|&(year, day)| input::day_input_path(year, day))
+            .collect(),
+        InputSource::Dir(dir) => days
+            .iter()
+            .flat_map(|&(year, day)| {
+                // Either layout might show up while watching
+                [
+                    input::input_path(dir, year, day),
+                    dir.join(format!("day_{}.txt", day)),
+                ]
+            })
+            .collect(),
+        InputSource::File(path) => vec![path.clone()],
+        InputSource::Stdin | InputSource::Text(_) => {
+            return Err("Only input files and directories that exist can be watched".to_string())
+        }
+    };
+    paths.sort();
+    paths.dedup();
+    Ok(paths)
+}
+
+/// Enough of a file's metadata to notice it was written to, `None` while it
+/// doesn't exist.
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Calls `on_change` right away and then whenever one of `paths` is created,
+/// written to or removed, checking every `interval`. Never returns.
+pub fn watch(paths: &[PathBuf], interval: Duration, mut on_change: impl FnMut()) -> ! {
+    let stamps = || paths.iter().map(|p| stamp(p)).collect::<Vec<_>>();
+    let mut last = stamps();
+
+    println!("Watching {} file(s), Ctrl-C to stop", paths.len());
+    for path in paths {
+        println!("  {}", path.display());
+    }
+    println!();
+    on_change();
+
+    loop {
+        std::thread::sleep(interval);
+        let current = stamps();
+        if current != last {
+            last = current;
+            println!(
+                "\n[{}] Input changed",
+                chrono::Local::now().format("%H:%M:%S")
+            );
+            on_change();
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::get_input_for_day;
 use crate::day::Day;
+use crate::error::{SolveError, SolveResult};
+use crate::utils::parse_lines;
 
 fn number_word_to_digit(number_word: &str) -> &str {
     let pos = NUMBERS.iter().position(|&x| x == number_word).unwrap();
@@ -49,34 +51,34 @@ impl Day for Day1 {
 
     get_input_for_day!(1);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let total: i64 = input
-            .lines()
-            .map(|line| {
-                let (first, last) = get_first_last(line, false);
-    
-                (first + &last).parse::<i64>().unwrap()
-            })
-            .sum();
-        total
+    fn part_1(&self, input: &str) -> SolveResult {
+        let values = parse_lines(input, |line| {
+            let (first, last) = get_first_last(line, false);
+
+            (first + &last)
+                .parse::<i64>()
+                .map_err(|_| SolveError::new(format!("No digits in {:?}", line)))
+        })?;
+        Ok(values.into_iter().sum::<i64>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let total: i64 = input
-            .lines()
-            .map(|line| {
-                let (first, last) = get_first_last(line, true);
-    
-                let first_digit = number_word_to_digit(&first);
-                let last_digit = number_word_to_digit(&last);
-    
-                (first_digit.to_string() + last_digit)
-                    .parse::<i64>()
-                    .unwrap()
-            })
-            .sum();
-    
-        total
+    fn part_2(&self, input: &str) -> SolveResult {
+        let values = parse_lines(input, |line| {
+            let (first, last) = get_first_last(line, true);
+
+            if first.is_empty() {
+                return Err(SolveError::new(format!("No digits in {:?}", line)));
+            }
+
+            let first_digit = number_word_to_digit(&first);
+            let last_digit = number_word_to_digit(&last);
+
+            (first_digit.to_string() + last_digit)
+                .parse::<i64>()
+                .map_err(SolveError::new)
+        })?;
+
+        Ok(values.into_iter().sum::<i64>().into())
     }
 }
 
@@ -84,6 +86,7 @@ impl Day for Day1 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_first_last_just_digits() {
@@ -109,18 +112,18 @@ mod tests {
     #[test]
     fn test_day_1_part_1() {
         let day = Day1;
-        let input = day.get_input();
-        let expected = 55208;
-        let actual = day.part_1(input);
+        let input = day.get_input().unwrap();
+        let expected = answers::expected(2023, 1, 1);
+        let actual = day.part_1(&input).unwrap();
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_day_1_part_2() {
         let day = Day1;
-        let input = day.get_input();
-        let expected = 54578;
-        let actual = day.part_2(input);
+        let input = day.get_input().unwrap();
+        let expected = answers::expected(2023, 1, 2);
+        let actual = day.part_2(&input).unwrap();
         assert_eq!(expected, actual);
     }
 }
\ No newline at end of file
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::{Direction, Grid, ToTile, grid::Position, dir::Movement, render::Colour}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MapTile {
+    Vertical,
+    Horizontal,
+    NeBend,
+    NwBend,
+    SeBend,
+    SwBend,
+    Start,
+    Empty
+}
+
+impl TryFrom<char> for MapTile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NeBend),
+            'J' => Ok(Self::NwBend),
+            '7' => Ok(Self::SwBend),
+            'F' => Ok(Self::SeBend),
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::Empty),
+            _ => Err(format!("Unknown tile: {}", c))
+        }
+    }
+}
+
+impl ToTile for MapTile {
+    fn to_tile(&self) -> char {
+        match self {
+            Self::Vertical => '|',
+            Self::Horizontal => '-',
+            Self::NeBend => 'L',
+            Self::NwBend => 'J',
+            Self::SwBend => '7',
+            Self::SeBend => 'F',
+            Self::Start => 'S',
+            Self::Empty => '.'
+        }
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        match self {
+            Self::Start => Some(Colour::Yellow),
+            Self::Empty => Some(Colour::Grey),
+            _ => None
+        }
+    }
+}
+
+impl MapTile {
+
+    pub fn determine_from_directions(a: &Direction, b: &Direction) -> Self {
+        match (a, b) {
+            (Direction::North, Direction::South) | (Direction::South, Direction::North) => Self::Vertical,
+            (Direction::East, Direction::West) | (Direction::West, Direction::East) => Self::Horizontal,
+            (Direction::North, Direction::East) | (Direction::East, Direction::North) => Self::NeBend,
+            (Direction::North, Direction::West) | (Direction::West, Direction::North) => Self::NwBend,
+            (Direction::South, Direction::East) | (Direction::East, Direction::South) => Self::SeBend,
+            (Direction::South, Direction::West) | (Direction::West, Direction::South) => Self::SwBend,
+            _ => panic!("Can't determine tile from directions")
+        }
+    }
+
+    pub fn has_direction(&self, direction: &Direction) -> bool {
+        match self {
+            Self::Vertical => matches!(direction, Direction::North | Direction::South),
+            Self::Horizontal => matches!(direction, Direction::East | Direction::West),
+            Self::NeBend => matches!(direction, Direction::North | Direction::East),
+            Self::NwBend => matches!(direction, Direction::North | Direction::West),
+            Self::SeBend => matches!(direction, Direction::South | Direction::East),
+            Self::SwBend => matches!(direction, Direction::South | Direction::West),
+            Self::Start => false,
+            Self::Empty => false
+        }
+    }
+
+    pub fn follow(&self, coming_from: Direction) -> SolveResult<Direction> {
+        match self {
+            Self::Vertical | Self::Horizontal => Ok(coming_from.opposite()),
+            Self::NeBend => Ok(coming_from.ninety_deg(matches!(coming_from, Direction::North))),
+            Self::NwBend => Ok(coming_from.ninety_deg(matches!(coming_from, Direction::West))),
+            Self::SeBend => Ok(coming_from.ninety_deg(matches!(coming_from, Direction::East))),
+            Self::SwBend => Ok(coming_from.ninety_deg(matches!(coming_from, Direction::South))),
+            Self::Start => Err(SolveError::new("Can't follow start tile as it's unknown")),
+            Self::Empty => Err(SolveError::new("Can't follow empty tile"))
+        }
+    }
+}
+
+type Map = Grid<MapTile>;
+
+fn find_start(map: &Map) -> SolveResult<Position> {
+    map.iter().find_map(|(p, tile)| if *tile == MapTile::Start { Some(p) } else { None }).or_missing("start tile")
+}
+
+fn get_connectors_of_tile(pos: Position, map: &Map) -> Vec<(Position, Direction)> {
+    let adjacents = map.get_direct_adjacents(pos);
+    adjacents.into_iter().filter(|(p, d)| map.get(*p).unwrap().has_direction(&d.opposite())).collect::<Vec<_>>()
+}
+
+fn follow_loop(map: &Map) -> SolveResult<HashMap<Position, MapTile>> {
+    let start_pos = find_start(map)?;
+    let connectors = get_connectors_of_tile(start_pos, map);
+    if connectors.len() != 2 {
+        return Err(SolveError::at(start_pos.1, start_pos.0, format!("Start tile has {} connecting pipes, expected 2", connectors.len())));
+    }
+    let start_tile = MapTile::determine_from_directions(&connectors[0].1, &connectors[1].1);
+
+    let mut current = connectors[0];
+    let tile = map.get(current.0).unwrap();
+
+    let mut loop_tiles = [(start_pos, start_tile), (current.0, *tile)].iter().copied().collect::<HashMap<_, _>>();
+
+    while current.0 != start_pos {
+        let current_tile = map.get(current.0).ok_or_else(|| SolveError::new("Loop leaves the map"))?;
+        let direction = current_tile.follow(current.1.opposite()).map_err(|e| SolveError::at(current.0.1, current.0.0, e.cause))?;
+        let next_pos = direction.add_to_pos(current.0);
+        loop_tiles.insert(current.0, *current_tile);
+        current = ((next_pos.0 as usize, next_pos.1 as usize), direction);
+    }
+
+    Ok(loop_tiles)
+}
+
+pub struct Day10;
+
+impl Day for Day10 {
+
+    get_input_for_day!(10);
+
+    fn part_1(&self, input: &str) -> SolveResult {
+        let map = Map::try_parse(input)?;
+
+        let tiles = follow_loop(&map)?;
+
+        Ok((tiles.len() / 2).into())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        let map = Map::try_parse(input)?;
+
+        let loop_tiles = follow_loop(&map)?;
+
+        Ok(map.rows().enumerate().map(|(y, row)| {
+            row.iter().enumerate().fold((0, false), |(count, toggle), (x, _)| {
+                if let Some(tile) = loop_tiles.get(&(x, y)) {
+                    if tile.has_direction(&Direction::South) {
+                        return (count, !toggle);
+                    }
+                } else if toggle {
+                    return (count + 1, toggle);
+                }
+                (count, toggle)
+            }).0
+        }).sum::<usize>().into())
+    }
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(Map::try_parse(input).map(drop))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    #[test]
+    fn test_part_1() {
+        let day = Day10;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 10, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = Day10;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 10, 2));
+    }
+
+    #[test]
+    fn test_render_loop() {
+        let map = Map::try_parse("7-F7-\n.FJ|7\nSJLL7\n|F--J\nLJ.LJ").unwrap();
+        let loop_tiles = follow_loop(&map).unwrap();
+        let junk = map.iter().map(|(p, _)| p).filter(|p| !loop_tiles.contains_key(p));
+        assert_eq!(map.render().mark(junk, '.').to_string(), "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...");
+    }
+
+    #[test]
+    fn test_malformed_map() {
+        let day = Day10;
+        let err = day.part_1("S-7\n|.|\nL-X").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(3)));
+        let err = day.part_2("S-7\n|.\nL-J").unwrap_err();
+        assert_eq!(err.line, Some(2));
+    }
+}
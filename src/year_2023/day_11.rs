@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{SolveError, SolveResult}, get_input_for_day};
 
 #[derive(Debug)]
 struct Universe {
@@ -14,13 +14,21 @@ impl Universe {
     const EMPTY: char = '.';
     const GALAXY: char = '#';
 
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> SolveResult<Self> {
         let grid = input
             .lines()
             .map(|line| line.chars().collect::<Vec<char>>())
             .collect::<Vec<Vec<char>>>();
 
-        Self { grid, expansion_rows: vec![], expansion_columns: vec![] }
+        let width = grid.first().map(|row| row.len()).unwrap_or(0);
+        if width == 0 {
+            return Err(SolveError::new("Universe is empty"));
+        }
+        if let Some(y) = grid.iter().position(|row| row.len() != width) {
+            return Err(SolveError::new(format!("Row is {} wide, expected {}", grid[y].len(), width)).on_line(y));
+        }
+
+        Ok(Self { grid, expansion_rows: vec![], expansion_columns: vec![] })
     }
 
     fn expand_rows(&mut self) {
@@ -98,32 +106,36 @@ impl Day for Day11 {
 
     get_input_for_day!(11);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let mut universe = Universe::parse(input);
+    fn part_1(&self, input: &str) -> SolveResult {
+        let mut universe = Universe::parse(input)?;
         universe.expand();
 
         let galaxies = universe.get_galaxies();
 
-        (galaxies.iter().flat_map(|g| {
+        Ok((galaxies.iter().flat_map(|g| {
             galaxies.iter().map(|g2| {
                 distance_between_galaxies(*g, *g2, 2, &universe)
             }).collect::<Vec<_>>()
-        }).sum::<usize>() / 2) as i64
+        }).sum::<usize>() / 2).into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let mut universe = Universe::parse(input);
+    fn part_2(&self, input: &str) -> SolveResult {
+        let mut universe = Universe::parse(input)?;
         universe.expand();
 
         const ONE_MILLION: usize = 1000000;
 
         let galaxies = universe.get_galaxies();
 
-        (galaxies.iter().flat_map(|g| {
+        Ok((galaxies.iter().flat_map(|g| {
             galaxies.iter().map(|g2| {
                 distance_between_galaxies(*g, *g2, ONE_MILLION, &universe)
             }).collect::<Vec<_>>()
-        }).sum::<usize>() / 2) as i64
+        }).sum::<usize>() / 2).into())
+    }
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(Universe::parse(input).map(drop))
     }
 }
 
@@ -131,19 +143,20 @@ impl Day for Day11 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day11;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 9536038);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 11, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day11;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 447744640566);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 11, 2));
     }
     
 }
@@ -1,6 +1,6 @@
 use std::mem::swap;
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveResult}, get_input_for_day, utils::{parse_lines, parse_number}};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum Spring {
@@ -26,22 +26,22 @@ struct SpringRow {
 }
 
 impl SpringRow {
-    fn parse(input: &str, part_2: bool) -> Self {
+    fn parse(input: &str, part_2: bool) -> SolveResult<Self> {
         let mut split = input.split(' ');
+        let initial = split.next().filter(|s| !s.is_empty()).or_missing("springs")?;
         let springs = if part_2 { 
-            let initial = split.next().unwrap();
             [initial; 5].join("?").chars().map(Spring::parse).collect()
         } else {
-            split.next().unwrap().chars().map(Spring::parse).collect() 
+            initial.chars().map(Spring::parse).collect() 
         };
-        let mut groups: Vec<_> = split.next().unwrap().split(',').map(|s| s.parse::<usize>().unwrap()).collect();
+        let mut groups: Vec<_> = split.next().or_missing("groups")?.split(',').map(parse_number).collect::<SolveResult<_>>()?;
         if part_2 {
             groups = groups.repeat(5)
         }
-        SpringRow {
+        Ok(SpringRow {
             springs,
             groups
-        }
+        })
     }
 
     fn find_combinations(&self) -> usize {
@@ -96,33 +96,38 @@ impl Day for Day12 {
 
     get_input_for_day!(12);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let rows = input.lines().map(|l| SpringRow::parse(l, false)).collect::<Vec<_>>();
-        rows.iter().map(|r| r.find_combinations()).sum::<usize>() as i64
+    fn part_1(&self, input: &str) -> SolveResult {
+        let rows = parse_lines(input, |l| SpringRow::parse(l, false))?;
+        Ok(rows.iter().map(|r| r.find_combinations()).sum::<usize>().into())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        let rows = parse_lines(input, |l| SpringRow::parse(l, true))?;
+        Ok(rows.iter().map(|r| r.find_combinations()).sum::<usize>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let rows = input.lines().map(|l| SpringRow::parse(l, true)).collect::<Vec<_>>();
-        rows.iter().map(|r| r.find_combinations()).sum::<usize>() as i64
+    fn parse(&self, part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, |l| SpringRow::parse(l, part == 2)).map(drop))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day12;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 7379);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 12, 1));
     }    
 
     #[test]
     fn test_part_2() {
         let day = Day12;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 7732028747925);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 12, 2));
     }
 
 }
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::{day::Day, get_input_for_day, utils::Grid};
+use crate::{day::Day, error::SolveResult, get_input_for_day, utils::{parse_sections, Grid}};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Tile {
@@ -8,12 +8,14 @@ enum Tile {
     Rock
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '.' => Self::Ash,
-            '#' => Self::Rock,
-            _ => panic!("Invalid tile")
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rock),
+            _ => Err(format!("Invalid tile: {}", c))
         }
     }
 }
@@ -26,10 +28,10 @@ struct TileGrid {
 
 impl TileGrid {
 
-    pub fn parse(input: &str) -> Self {
-        Self {
-            tiles: Tiles::parse(input)
-        }
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        Ok(Self {
+            tiles: Tiles::try_parse(input)?
+        })
     }
 
     fn count_differences_in_row(row_1: &[&Tile], row_2: &[&Tile]) -> usize {
@@ -94,16 +96,20 @@ impl Day for Day13 {
 
     get_input_for_day!(13);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let grids = input.split("\n\n").map(TileGrid::parse).collect::<Vec<_>>();
+    fn part_1(&self, input: &str) -> SolveResult {
+        let grids = parse_sections(input, TileGrid::parse)?;
 
-        grids.iter().map(|grid| grid.find_reflection_summaries(0)).sum::<usize>() as i64
+        Ok(grids.iter().map(|grid| grid.find_reflection_summaries(0)).sum::<usize>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let grids = input.split("\n\n").map(TileGrid::parse).collect::<Vec<_>>();
+    fn part_2(&self, input: &str) -> SolveResult {
+        let grids = parse_sections(input, TileGrid::parse)?;
+
+        Ok(grids.iter().map(|grid| grid.find_reflection_summaries(1)).sum::<usize>().into())
+    }
 
-        grids.iter().map(|grid| grid.find_reflection_summaries(1)).sum::<usize>() as i64
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_sections(input, TileGrid::parse).map(drop))
     }
 }
 
@@ -112,19 +118,20 @@ impl Day for Day13 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day13;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 34993);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 13, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day13;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 29341);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 13, 2));
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::{day::Day, error::SolveResult, get_input_for_day, utils::{Grid, ToTile, grid::Position, render::Colour}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Round,
+    Square
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Square),
+            'O' => Ok(Tile::Round),
+            _ => Err(format!("Unknown tile: {}", c)),
+        }
+    }
+}
+
+impl ToTile for Tile {
+    fn to_tile(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Square => '#',
+            Tile::Round => 'O',
+        }
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        match self {
+            Tile::Round => Some(Colour::Yellow),
+            _ => None,
+        }
+    }
+}
+
+type Tiles = Grid<Tile>;
+
+/// Rolls every round rock north until it hits the edge, a square rock or
+/// another round rock that has already stopped.
+fn tilt_north(grid: &mut Tiles) {
+    for x in 0..grid.width {
+        let mut free = 0;
+        for y in 0..grid.height {
+            match grid.get((x, y)) {
+                Some(Tile::Square) => free = y + 1,
+                Some(Tile::Round) => {
+                    grid.swap((x, y), (x, free));
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east by turning the platform so the next
+/// direction is always north, ending up back the way it started.
+fn spin_cycle(grid: &mut Tiles) {
+    for _ in 0..4 {
+        tilt_north(grid);
+        grid.rotate_cw();
+    }
+}
+
+fn north_load(grid: &Tiles) -> usize {
+    grid.rows().enumerate().map(|(y, row)| {
+        row.iter().filter(|t| **t == Tile::Round).count() * (grid.height - y)
+    }).sum()
+}
+
+fn hash_rounds(rounds: &[Position]) -> u64 {
+    rounds.iter().fold(0, |acc, (x, y)| {
+        acc + (x + y * 100) as u64
+    })
+}
+
+fn get_round_positions(grid: &Tiles) -> Vec<Position> {
+    grid.iter().filter_map(|(p, c)| if c == &Tile::Round { Some(p) } else { None }).collect::<Vec<_>>()
+}
+
+pub struct Day14;
+
+
+impl Day for Day14 {
+
+    get_input_for_day!(14);
+
+    fn part_1(&self, input: &str) -> SolveResult {
+        let mut grid = Grid::<Tile>::try_parse(input)?;
+        tilt_north(&mut grid);
+
+        Ok(north_load(&grid).into())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        
+        const TIMES: usize = 1000000000;
+        
+        let mut grid = Grid::<Tile>::try_parse(input)?;
+
+        let mut seen_states = HashMap::new();
+
+        let mut pos = 0;
+
+        while pos < TIMES {
+            spin_cycle(&mut grid);
+
+            pos += 1;
+
+            let hash = hash_rounds(&get_round_positions(&grid));
+
+            let cycle_start = seen_states.get(&hash);
+
+            if let Some(start) = cycle_start {
+                let cycle_length = pos - *start;
+                let to_do = (TIMES - pos) / cycle_length;
+                pos += to_do * cycle_length;
+            } else {
+                seen_states.insert(hash, pos);
+            }
+
+        }
+
+        Ok(north_load(&grid).into())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers;
+
+    #[test]
+    fn test_hash_rounds() {
+        let rounds = vec![(0, 0), (1, 0), (2, 0), (3, 0)];
+        let rounds_2 = vec![(1, 0), (0, 0), (2, 0), (3, 0)];
+        assert_eq!(hash_rounds(&rounds), hash_rounds(&rounds_2));
+    }
+
+    #[test]
+    fn test_tilt_north() {
+        let mut tiles = Tiles::try_parse("O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....").unwrap();
+        tilt_north(&mut tiles);
+        assert_eq!(tiles.to_string(), "OOOO.#.O..\nOO..#....#\nOO..O##..O\nO..#.OO...\n........#.\n..#....#.#\n..O..#.O.O\n..O.......\n#....###..\n#....#....");
+    }
+
+    #[test]
+    fn test_part_1() {
+        let day = Day14;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 14, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = Day14;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 14, 2));
+    }
+
+}
\ No newline at end of file
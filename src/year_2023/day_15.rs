@@ -1,4 +1,4 @@
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{SolveError, SolveResult}, get_input_for_day, utils::parse_number};
 
 fn hash(input: &str) -> u64 {
     input.chars().fold(0, |acc, c| {
@@ -20,22 +20,24 @@ struct Step {
 }
 
 impl Step {
-    pub fn parse(input: &str) -> Self {
-        if let Some(equal_pos) = input.find(|c| c == '=') {
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        if let Some(equal_pos) = input.find('=') {
             let label = input[..equal_pos].trim();
             let val = input[equal_pos + 1..].trim();
-            Self {
+            Ok(Self {
                 label: label.to_string(),
                 address: hash(label),
-                operation: Operation::Update(val.parse().unwrap()),
-            }
-        } else {
-            let label = input[..input.len() - 1].trim();
-            Self {
+                operation: Operation::Update(parse_number(val)?),
+            })
+        } else if let Some(label) = input.strip_suffix('-') {
+            let label = label.trim();
+            Ok(Self {
                 label: label.to_string(),
                 address: hash(label),
                 operation: Operation::Remove,
-            }
+            })
+        } else {
+            Err(SolveError::at_token(input, format!("Invalid step: {}", input)))
         }
     }
 }
@@ -46,14 +48,14 @@ impl Day for Day15 {
 
     get_input_for_day!(15);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         let split = input.split(',').map(|s| s.trim()).collect::<Vec<_>>();
-        split.iter().map(|s| hash(s)).sum::<u64>() as i64
+        Ok(split.iter().map(|s| hash(s)).sum::<u64>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
         let split = input.split(',').map(|s| s.trim()).collect::<Vec<_>>();
-        let steps = split.iter().map(|s| Step::parse(s)).collect::<Vec<_>>();
+        let steps = split.iter().map(|s| Step::parse(s)).collect::<SolveResult<Vec<_>>>()?;
 
         let mut boxes: Vec<Vec<(String, u64)>> = vec![vec![]; 256];
 
@@ -79,11 +81,11 @@ impl Day for Day15 {
             }
         }
 
-        boxes.iter().enumerate().map(|(bi, b)| {
+        Ok(boxes.iter().enumerate().map(|(bi, b)| {
             b.iter().enumerate().map(|(li, l)| {
                 (bi + 1) * (li + 1) * (l.1 as usize)
             }).sum::<usize>()
-        }).sum::<usize>() as i64
+        }).sum::<usize>().into())
     }
 }
 
@@ -92,19 +94,20 @@ impl Day for Day15 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day15;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 509167);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 15, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day15;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 259333);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 15, 2));
     }
 
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::{day::Day, get_input_for_day, utils::{grid::{GridPointer, Position}, Direction, Grid}};
+use crate::{day::Day, error::{OrMissing, SolveResult}, get_input_for_day, utils::{grid::{GridPointer, Position}, render::Colour, Direction, Grid, ToTile}};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Mirror {
@@ -21,15 +21,36 @@ enum Tile {
     Splitter(Splitter),
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '.' => Self::Empty,
-            '/' => Self::Mirror(Mirror::Forward),
-            '\\' => Self::Mirror(Mirror::Backward),
-            '|' => Self::Splitter(Splitter::Vertical),
-            '-' => Self::Splitter(Splitter::Horizontal),
-            _ => panic!("Invalid tile: {}", c),
+            '.' => Ok(Self::Empty),
+            '/' => Ok(Self::Mirror(Mirror::Forward)),
+            '\\' => Ok(Self::Mirror(Mirror::Backward)),
+            '|' => Ok(Self::Splitter(Splitter::Vertical)),
+            '-' => Ok(Self::Splitter(Splitter::Horizontal)),
+            _ => Err(format!("Invalid tile: {}", c)),
+        }
+    }
+}
+
+impl ToTile for Tile {
+    fn to_tile(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Mirror(Mirror::Forward) => '/',
+            Self::Mirror(Mirror::Backward) => '\\',
+            Self::Splitter(Splitter::Vertical) => '|',
+            Self::Splitter(Splitter::Horizontal) => '-',
+        }
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        match self {
+            Self::Empty => None,
+            _ => Some(Colour::Cyan),
         }
     }
 }
@@ -110,7 +131,7 @@ impl Beam {
 
 }
 
-fn get_energized(mut starting_beam: Beam, tiles: &Tiles) -> i64 {
+fn get_energized(mut starting_beam: Beam, tiles: &Tiles) -> HashSet<Position> {
     let other_starting = starting_beam.advance(tiles, true).unwrap();
 
     let mut beams = if let Some(other_starting) = other_starting {
@@ -144,7 +165,7 @@ fn get_energized(mut starting_beam: Beam, tiles: &Tiles) -> i64 {
         }).flatten().collect();
     }
 
-    visited_poses.len() as i64
+    visited_poses
 }
 
 pub struct Day16;
@@ -153,18 +174,18 @@ impl Day for Day16 {
 
     get_input_for_day!(16);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let tiles = Grid::parse(input);
+    fn part_1(&self, input: &str) -> SolveResult {
+        let tiles = Grid::try_parse(input)?;
         let starting_beam = Beam(GridPointer::zero());
-        get_energized(starting_beam, &tiles)
+        Ok((get_energized(starting_beam, &tiles).len() as i64).into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let tiles = Grid::parse(input);
+    fn part_2(&self, input: &str) -> SolveResult {
+        let tiles = Grid::try_parse(input)?;
 
         let (width, height) = tiles.size();
 
-        (0..width).map(|x| {
+        Ok((0..width).map(|x| {
 
             let heights = if x == 0 || x == width - 1 { (0..height).collect() } else { vec![0,  height - 1] };
 
@@ -185,12 +206,16 @@ impl Day for Day16 {
 
                 to_check.into_iter().map(|dir| {
                     let starting_beam = Beam::new((x, y), dir);
-                    get_energized(starting_beam, &tiles)
+                    get_energized(starting_beam, &tiles).len() as i64
                 }).max().unwrap()
             }).max().unwrap()
-        }).max().unwrap()
+        }).max().or_missing("tiles")?.into())
     }
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(Tiles::try_parse(input).map(drop))
+    }
 }
 
 
@@ -198,19 +223,28 @@ impl Day for Day16 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day16;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 7060);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 16, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day16;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 7493);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 16, 2));
+    }
+
+    #[test]
+    fn test_render_energized() {
+        let tiles = Tiles::try_parse(".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....").unwrap();
+        let energized = get_energized(Beam(GridPointer::zero()), &tiles);
+        let render = tiles.render().mark(tiles.iter().map(|(p, _)| p), '.').mark(energized, '#');
+        assert_eq!(render.to_string(), "######....\n.#...#....\n.#...#####\n.#...##...\n.#...##...\n.#...##...\n.#..####..\n########..\n.#######..\n.#...#.#..");
     }
 
 }
\ No newline at end of file
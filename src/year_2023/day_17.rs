@@ -1,12 +1,14 @@
 use std::collections::{HashMap, BinaryHeap};
 
-use crate::{day::Day, get_input_for_day, utils::{Grid, grid::Position, Direction, dir::CARDINALS}};
+use crate::{day::Day, error::{SolveError, SolveResult}, get_input_for_day, utils::{Grid, grid::Position, Direction, dir::CARDINALS}};
 
 struct CityBlock(usize);
 
-impl From<char> for CityBlock {
-    fn from(c: char) -> Self {
-        Self(c.to_digit(10).unwrap() as usize)
+impl TryFrom<char> for CityBlock {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.to_digit(10).map(|d| Self(d as usize)).ok_or_else(|| format!("Invalid heat loss: {}", c))
     }
 }
 
@@ -38,8 +40,8 @@ impl PartialEq for Node {
 
 impl Eq for Node {}
 
-fn calc_path(input: &str, min: usize, max: usize) -> i64 {
-    let grid = CityGrid::parse(input);
+fn calc_path(input: &str, min: usize, max: usize) -> SolveResult {
+    let grid = CityGrid::try_parse(input)?;
 
     let target = (grid.width - 1, grid.height - 1);
 
@@ -56,7 +58,7 @@ fn calc_path(input: &str, min: usize, max: usize) -> i64 {
 
     while let Some(node) = frontier.pop() {
         if node.pos == target {
-            return -node.cost;
+            return Ok((-node.cost).into());
         }
 
         if node.pos != start && distances.get(&(node.pos, node.dir.unwrap())).map(|&c| -node.cost > c).unwrap_or(false) {
@@ -87,7 +89,7 @@ fn calc_path(input: &str, min: usize, max: usize) -> i64 {
         }
     }
 
-    unreachable!("No path found");
+    Err(SolveError::new("No path found"))
 }
 
 pub struct Day17;
@@ -96,11 +98,11 @@ impl Day for Day17 {
 
     get_input_for_day!(17);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         calc_path(input, 1, 3)
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
         calc_path(input, 4, 10)
     }
 }
@@ -110,19 +112,20 @@ impl Day for Day17 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day17;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 638);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 17, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day17;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 748);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 17, 2));
     }
 
 }
\ No newline at end of file
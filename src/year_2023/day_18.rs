@@ -0,0 +1,131 @@
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::{Direction, dir::Movement, parse_lines, parse_number}};
+
+pub struct Instruction {
+    dir: Direction,
+    amount: usize,
+}
+
+impl Instruction {
+
+    fn char_to_dir(s: &str) -> SolveResult<Direction> {
+        match s {
+            "U" => Ok(Direction::North),
+            "D" => Ok(Direction::South),
+            "L" => Ok(Direction::West),
+            "R" => Ok(Direction::East),
+            _ => Err(SolveError::at_token(s, format!("Invalid direction char: {}", s)))
+        }
+    }
+
+    fn num_to_dir(s: &str) -> SolveResult<Direction> {
+        match s {
+            "0" => Ok(Direction::East),
+            "1" => Ok(Direction::South),
+            "2" => Ok(Direction::West),
+            "3" => Ok(Direction::North),
+            _ => Err(SolveError::at_token(s, format!("Invalid direction number: {}", s)))
+        }
+    }
+
+    pub fn parse(input: &str, part_2: bool) -> SolveResult<Self> {
+        let split = input.split(' ').collect::<Vec<&str>>();
+
+        let (dir, amount) = if part_2 {
+            let color = split.get(2).or_missing("color")?.trim_matches(|c| c == '(' || c ==')' || c == '#');
+            if color.len() != 6 || !color.is_ascii() {
+                return Err(SolveError::at_token(color, format!("Invalid color: {}", color)));
+            }
+            let amount = usize::from_str_radix(&color[0..5], 16).map_err(|e| SolveError::at_token(color, e))?;
+            (Self::num_to_dir(&color[5..6])?, amount)
+        } else {
+            let dir = Self::char_to_dir(split[0])?;
+            let amount = parse_number(split.get(1).or_missing("amount")?)?;
+            (dir, amount)
+        };
+
+        Ok(Self {
+            dir,
+            amount,
+        })
+    }
+
+}
+
+pub struct Day18;
+
+impl Day for Day18 {
+
+    get_input_for_day!(18);
+
+    fn part_1(&self, input: &str) -> SolveResult {
+        let instructions = parse_lines(input, |line| Instruction::parse(line, false))?;
+
+        let start = (0, 0);
+
+        let mut verts: Vec<(isize, isize)> = vec![start];
+
+        let mut pos = start;
+
+        for ins in instructions.into_iter() {
+            let next_pos = ins.dir.add_to_pos_times_negative(pos, ins.amount as isize);
+            pos = next_pos;
+            verts.push(pos);
+        }
+
+        let area = verts.windows(2).map(|w| ((w[0].0) * (w[1].1)) - ((w[0].1) * (w[1].0))).sum::<isize>() / 2;
+
+        let perimeter = verts.windows(2).map(|w| (w[0].0 - w[1].0).abs() + (w[0].1 - w[1].1).abs()).sum::<isize>();
+
+        Ok(((area + 1) + (perimeter / 2)).into())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        let instructions = parse_lines(input, |line| Instruction::parse(line, true))?;
+
+        let start = (0, 0);
+
+        let mut verts: Vec<(isize, isize)> = vec![start];
+
+        let mut pos = start;
+
+        for ins in instructions.into_iter() {
+            let next_pos = ins.dir.add_to_pos_times_negative(pos, ins.amount as isize);
+            pos = next_pos;
+            verts.push(pos);
+        }
+
+        let area = verts.windows(2).map(|w| ((w[0].0) * (w[1].1)) - ((w[0].1) * (w[1].0))).sum::<isize>() / 2;
+
+        let perimeter = verts.windows(2).map(|w| (w[0].0 - w[1].0).abs() + (w[0].1 - w[1].1).abs()).sum::<isize>();
+
+        Ok(((area + 1) + (perimeter / 2)).into())
+    }
+
+
+    fn parse(&self, part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, |line| Instruction::parse(line, part == 2)).map(drop))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::answers;
+
+    #[test]
+    fn test_part_1() {
+        let day = Day18;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 18, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = Day18;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 18, 2));
+    }
+
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, ops::Range};
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::{parse_lines, parse_number}};
 
 type FactorVal = u32;
 
@@ -17,13 +17,13 @@ enum PartFactor {
 }
 
 impl PartFactor {
-    fn parse(c: char) -> Self {
-        match c {
-            'x' => Self::Cool,
-            'm' => Self::Musical,
-            'a' => Self::Aerodynamic,
-            's' => Self::Shiny,
-            _ => panic!("Invalid part factor {}", c)
+    fn parse(s: &str) -> SolveResult<Self> {
+        match s {
+            "x" => Ok(Self::Cool),
+            "m" => Ok(Self::Musical),
+            "a" => Ok(Self::Aerodynamic),
+            "s" => Ok(Self::Shiny),
+            _ => Err(SolveError::at_token(s, format!("Invalid part factor {}", s)))
         }
     }
 
@@ -61,11 +61,11 @@ enum Check {
 }
 
 impl Check {
-    fn parse(c: char) -> Self {
-        match c {
-            '>' => Self::Greater,
-            '<' => Self::Less,
-            _ => panic!("Invalid check {}", c)
+    fn parse(s: &str) -> SolveResult<Self> {
+        match s {
+            ">" => Ok(Self::Greater),
+            "<" => Ok(Self::Less),
+            _ => Err(SolveError::at_token(s, format!("Invalid check {}", s)))
         }
     }
 }
@@ -79,17 +79,19 @@ struct Condition {
 
 impl Condition {
 
-    fn parse(input: &str) -> Self {
-        let chars = input.chars().collect::<Vec<char>>();
-        let factor = PartFactor::parse(chars[0]);
-        let check = Check::parse(chars[1]);
-        let value = chars[2..].iter().collect::<String>().parse::<FactorVal>().unwrap();
+    fn parse(input: &str) -> SolveResult<Self> {
+        if input.len() < 3 || !input.is_ascii() {
+            return Err(SolveError::at_token(input, format!("Invalid condition {}", input)));
+        }
+        let factor = PartFactor::parse(&input[0..1])?;
+        let check = Check::parse(&input[1..2])?;
+        let value = parse_number(&input[2..])?;
 
-        Self {
+        Ok(Self {
             check,
             factor,
             value
-        }
+        })
     }
 
     fn evaluate(&self, part: &Part) -> bool {
@@ -124,20 +126,19 @@ struct WorkflowStep {
 
 impl WorkflowStep {
 
-    fn parse(input: &str) -> Self {
-        if input.contains(':') {
-            let split = input.split(':').collect::<Vec<&str>>();
-            let cond = Condition::parse(split[0]);
-            let result = WorkflowResult::parse(split[1]);
-            Self {
+    fn parse(input: &str) -> SolveResult<Self> {
+        if let Some((cond, result)) = input.split_once(':') {
+            let cond = Condition::parse(cond)?;
+            let result = WorkflowResult::parse(result);
+            Ok(Self {
                 cond: Some(cond),
                 result
-            }
+            })
         } else {
-            Self {
+            Ok(Self {
                 cond: None,
                 result: WorkflowResult::parse(input)
-            }
+            })
         }
     }
 
@@ -219,16 +220,20 @@ struct Workflow {
 
 impl Workflow {
 
-    fn parse(input: &str) -> Self {
-        let split = input.split('{').collect::<Vec<&str>>();
-        let name = split[0].trim().to_string();
+    fn parse(input: &str) -> SolveResult<Self> {
+        let (name, steps) = input.split_once('{').or_missing("workflow steps")?;
+        let name = name.trim().to_string();
+
+        let steps = steps.trim_end_matches('}').split(',').map(WorkflowStep::parse).collect::<SolveResult<Vec<_>>>()?;
 
-        let steps = split[1].trim_end_matches('}').split(',').map(WorkflowStep::parse).collect();
+        if steps.last().map(|s| s.cond.is_some()).unwrap_or(true) {
+            return Err(SolveError::at_token(input, format!("Workflow {} has no fallback step", name)));
+        }
 
-        Self {
+        Ok(Self {
             name,
             steps
-        }
+        })
     }
 
     fn process(&self, part: &Part) -> Option<WorkflowResult> {
@@ -248,71 +253,90 @@ struct Part {
 
 impl Part {
 
-    fn parse(input: &str) -> Self {
+    fn parse(input: &str) -> SolveResult<Self> {
         let input = input.trim_matches(|c| c == '{' || c =='}');
 
         let pairs = input.split(',').map(|p| {
-            let split = p.split('=').collect::<Vec<&str>>();
-            let factor = PartFactor::parse(split[0].chars().next().unwrap());
-            let value = split[1].parse::<FactorVal>().unwrap();
+            let (factor, value) = p.split_once('=').or_missing("part rating")?;
+            let factor = PartFactor::parse(factor)?;
+            let value = parse_number(value)?;
 
-            (factor, value)
-        }).collect();
+            Ok((factor, value))
+        }).collect::<SolveResult<_>>()?;
 
-        Self {
+        Ok(Self {
             factors: pairs
-        }
+        })
     }
 
 }
 
+fn parse_workflows(input: &str) -> SolveResult<HashMap<String, Workflow>> {
+    let workflows = parse_lines(input, Workflow::parse)?;
+    let names = workflows.iter().map(|w| w.name.as_str()).collect::<Vec<_>>();
+    for (i, w) in workflows.iter().enumerate() {
+        for step in w.steps.iter() {
+            if let WorkflowResult::Jump(target) = &step.result {
+                if !names.contains(&target.as_str()) {
+                    return Err(SolveError::new(format!("Unknown workflow {}", target)).on_line(i));
+                }
+            }
+        }
+    }
+    Ok(workflows.into_iter().map(|w| (w.name.clone(), w)).collect())
+}
+
 pub struct Day19;
 
 impl Day for Day19 {
 
     get_input_for_day!(19);
     
-    fn part_1(&self, input: &str) -> i64 {
-        let split = input.split("\n\n").collect::<Vec<&str>>();
+    fn part_1(&self, input: &str) -> SolveResult {
+        let (workflows, parts) = input.split_once("\n\n").or_missing("parts after the workflows")?;
+
+        let workflows = parse_workflows(workflows)?;
+        let parts = parse_lines(parts, Part::parse).map_err(|e| e.shift_lines(workflows.len() + 1))?;
 
-        let workflows = split[0].split('\n').map(Workflow::parse).map(|w| (w.name.clone(), w)).collect::<HashMap<_, _>>();
-        let parts = split[1].split('\n').map(Part::parse).collect::<Vec<Part>>();
+        let mut total = 0;
 
-        let accepted = parts.iter().filter(|p| {
-            let mut workflow = workflows.get("in").unwrap();
+        for p in parts.iter() {
+            let mut workflow = workflows.get("in").or_missing("workflow in")?;
 
-            loop {
+            let accepted = loop {
                 match workflow.process(p) {
-                    Some(WorkflowResult::Accept) => return true,
-                    Some(WorkflowResult::Reject) => return false,
+                    Some(WorkflowResult::Accept) => break true,
+                    Some(WorkflowResult::Reject) => break false,
                     Some(WorkflowResult::Jump(name)) => {
-                        workflow = workflows.get(&name).unwrap();
+                        workflow = workflows.get(&name).ok_or_else(|| SolveError::new(format!("Unknown workflow {}", name)))?;
                     },
-                    None => return false
+                    None => break false
                 }
+            };
+
+            if accepted {
+                total += p.factors.values().sum::<FactorVal>() as i64;
             }
-        });
+        }
 
-        accepted.map(|p| {
-            p.factors.values().sum::<FactorVal>() as i64
-        }).sum()
+        Ok(total.into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
                 
-        let split = input.split("\n\n").collect::<Vec<&str>>();
+        let workflows = input.split("\n\n").next().or_missing("workflows")?;
 
-        let workflows = split[0].split('\n').map(Workflow::parse).map(|w| (w.name.clone(), w)).collect::<HashMap<_, _>>();
+        let workflows = parse_workflows(workflows)?;
         
         let possible = vec![PartFactor::possible_map()];
 
-        let workflow = workflows.get("in").unwrap();
+        let workflow = workflows.get("in").or_missing("workflow in")?;
 
-        let accepted = workflow.steps[0].dfs_accepted(possible, &workflows, Some(workflow.steps[1..].iter().collect())).unwrap();
+        let accepted = workflow.steps[0].dfs_accepted(possible, &workflows, Some(workflow.steps[1..].iter().collect())).unwrap_or_default();
 
-        accepted.iter().map(|p| {
+        Ok(accepted.iter().map(|p| {
             p.values().map(|r| (r.end - r.start) as i64).product::<i64>()
-        }).sum::<i64>()
+        }).sum::<i64>().into())
     }
 }
 
@@ -321,19 +345,20 @@ impl Day for Day19 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day19;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 377025);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 19, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day19;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 135506683246673);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 19, 2));
     }
 
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::{parse_lines, parse_number}};
 
 #[derive(Debug, PartialEq, Eq)]
 struct Draw {
@@ -8,16 +8,16 @@ struct Draw {
 }
 
 impl Draw {
-    fn parse_section(raw: &str) -> (String, u32) {
+    fn parse_section(raw: &str) -> SolveResult<(String, u32)> {
         let raw = raw.trim().split(' ').collect::<Vec<&str>>();
 
-        let number = raw.first().unwrap().parse::<u32>().unwrap();
-        let color = raw.get(1).unwrap();
+        let number = parse_number(raw.first().or_missing("cube count")?)?;
+        let color = raw.get(1).or_missing("cube color")?;
 
-        (color.to_string(), number)
+        Ok((color.to_string(), number))
     }
 
-    pub fn parse(raw: &str) -> Self {
+    pub fn parse(raw: &str) -> SolveResult<Self> {
         let sections = raw.split(", ").collect::<Vec<&str>>();
 
         let mut red = 0;
@@ -25,17 +25,17 @@ impl Draw {
         let mut blue = 0;
 
         for section in sections {
-            let (color, number) = Draw::parse_section(section);
+            let (color, number) = Draw::parse_section(section)?;
 
             match color.as_str() {
                 "red" => red += number,
                 "green" => green += number,
                 "blue" => blue += number,
-                _ => panic!("Invalid color: {}", color),
+                _ => return Err(SolveError::at_token(section, format!("Invalid color: {}", color))),
             }
         }
 
-        Draw { red, green, blue }
+        Ok(Draw { red, green, blue })
     }
 }
 
@@ -46,18 +46,18 @@ struct Game {
 }
 
 impl Game {
-    pub fn parse(raw: &str) -> Self {
+    pub fn parse(raw: &str) -> SolveResult<Self> {
         let s = raw.split(": ").collect::<Vec<&str>>();
 
-        let id = s.first().unwrap().split_once(' ').unwrap().1.trim().parse::<u32>().unwrap();
+        let id = parse_number(s.first().and_then(|g| g.split_once(' ')).or_missing("game id")?.1)?;
         let draws = s
             .get(1)
-            .unwrap()
+            .or_missing("draws")?
             .split(';')
             .map(Draw::parse)
-            .collect::<Vec<Draw>>();
+            .collect::<SolveResult<Vec<Draw>>>()?;
 
-        Self { id, draws }
+        Ok(Self { id, draws })
     }
 
     pub fn get_totals(&self) -> Vec<(u32, u32, u32)> {
@@ -91,12 +91,11 @@ impl Day for Day2 {
 
     get_input_for_day!(2);
 
-    fn part_1(&self, input: &str) -> i64 {    
+    fn part_1(&self, input: &str) -> SolveResult {    
         const MAXES: (u32, u32, u32) = (12, 13, 14);
         let mut total = 0;
     
-        for line in input.lines() {
-            let game = Game::parse(line);
+        for game in parse_lines(input, Game::parse)? {
             let draw_totals = game.get_totals();
     
             if draw_totals
@@ -106,20 +105,19 @@ impl Day for Day2 {
                 total += game.id;
             }
         }
-        total.try_into().unwrap()
+        Ok(total.into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
         let mut total = 0;
     
-        for line in input.lines() {
-            let game = Game::parse(line);
+        for game in parse_lines(input, Game::parse)? {
             let draw_totals = game.get_totals_three_lists();
     
             let maxes = (
-                draw_totals.0.iter().max().unwrap(),
-                draw_totals.1.iter().max().unwrap(),
-                draw_totals.2.iter().max().unwrap(),
+                draw_totals.0.iter().max().or_missing("draws")?,
+                draw_totals.1.iter().max().or_missing("draws")?,
+                draw_totals.2.iter().max().or_missing("draws")?,
             );
     
             let power = maxes.0 * maxes.1 * maxes.2;
@@ -127,30 +125,35 @@ impl Day for Day2 {
             total += power;
         }
     
-        total.try_into().unwrap()
+        Ok(total.into())
     }    
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, Game::parse).map(drop))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_parse_section() {
         let raw = "1 red";
         let expected = ("red".to_string(), 1);
-        let actual = Draw::parse_section(raw);
+        let actual = Draw::parse_section(raw).unwrap();
         assert_eq!(expected, actual);
 
         let raw = "2 green";
         let expected = ("green".to_string(), 2);
-        let actual = Draw::parse_section(raw);
+        let actual = Draw::parse_section(raw).unwrap();
         assert_eq!(expected, actual);
 
         let raw = "3 blue";
         let expected = ("blue".to_string(), 3);
-        let actual = Draw::parse_section(raw);
+        let actual = Draw::parse_section(raw).unwrap();
         assert_eq!(expected, actual);
     }
 
@@ -162,7 +165,7 @@ mod tests {
             green: 2,
             blue: 3,
         };
-        let actual = Draw::parse(raw);
+        let actual = Draw::parse(raw).unwrap();
         assert_eq!(expected, actual);
     }
 
@@ -184,25 +187,25 @@ mod tests {
                 },
             ],
         };
-        let actual = Game::parse(raw);
+        let actual = Game::parse(raw).unwrap();
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_day_2_part_1() {
         let day = Day2;
-        let input = day.get_input();
-        let expected = 2164;
-        let actual = day.part_1(input);
+        let input = day.get_input().unwrap();
+        let expected = answers::expected(2023, 2, 1);
+        let actual = day.part_1(&input).unwrap();
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_day_2_part_2() {
         let day = Day2;
-        let input = day.get_input();
-        let expected = 69929;
-        let actual = day.part_2(input);
+        let input = day.get_input().unwrap();
+        let expected = answers::expected(2023, 2, 2);
+        let actual = day.part_2(&input).unwrap();
         assert_eq!(expected, actual);
     }
 }
\ No newline at end of file
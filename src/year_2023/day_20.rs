@@ -1,6 +1,6 @@
 use std::{collections::HashMap, cmp::{max, min}};
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::parse_lines};
 
 #[derive(Debug)]
 pub enum ModuleLogic {
@@ -23,10 +23,10 @@ impl ModuleLogic {
         Self::Conjunction(HashMap::new())
     }
 
-    pub fn unwrap_conj(&self) -> &HashMap<String, bool> {
+    pub fn unwrap_conj(&self) -> SolveResult<&HashMap<String, bool>> {
         match self {
-            Self::Conjunction(inputs) => inputs,
-            _ => panic!("Not a conjunction")
+            Self::Conjunction(inputs) => Ok(inputs),
+            _ => Err(SolveError::new("Not a conjunction"))
         }
     }
 
@@ -71,13 +71,13 @@ pub struct Module {
 
 impl Module {
 
-    pub fn parse(input: &str) -> Self {
+    pub fn parse(input: &str) -> SolveResult<Self> {
 
         let mut parts = input.split(" -> ");
         
-        let header = parts.next().unwrap();
+        let header = parts.next().or_missing("module name")?;
 
-        let logic_char = header.chars().nth(0).unwrap();
+        let logic_char = header.chars().nth(0).or_missing("module name")?;
 
         let logic = match logic_char {
             '%' => ModuleLogic::flip_flop(),
@@ -92,13 +92,13 @@ impl Module {
 
         let id = header[id_range].to_string();
 
-        let targets = parts.next().unwrap().split(", ").map(|s| s.to_string()).collect();
+        let targets = parts.next().or_missing("module targets")?.split(", ").map(|s| s.to_string()).collect();
 
-        Self {
+        Ok(Self {
             id,
             logic,
             targets
-        }
+        })
     }
 
     pub fn process(&mut self, from: String, freq: bool) -> Option<bool> {
@@ -130,11 +130,11 @@ impl Day for Day20 {
 
     get_input_for_day!(20);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         
         const TIMES: usize = 1000;
         
-        let modules = input.lines().map(Module::parse).collect::<Vec<_>>();
+        let modules = parse_lines(input, Module::parse)?;
 
         let name_targets = modules.iter().map(|m| (m.id.clone(), m.targets.clone())).collect::<Vec<_>>();
 
@@ -167,11 +167,11 @@ impl Day for Day20 {
             }
         }
 
-        sent.0 * sent.1
+        Ok((sent.0 * sent.1).into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {        
-        let modules = input.lines().map(Module::parse).collect::<Vec<_>>();
+    fn part_2(&self, input: &str) -> SolveResult {        
+        let modules = parse_lines(input, Module::parse)?;
 
         let name_targets = modules.iter().map(|m| (m.id.clone(), m.targets.clone())).collect::<Vec<_>>();
 
@@ -180,9 +180,9 @@ impl Day for Day20 {
             (m.id.to_string(), m)
         }).collect::<HashMap<_, _>>();
 
-        let silly_goose = name_targets.into_iter().find(|(_, targets)| targets.contains(&"rx".to_string())).unwrap().0;
+        let silly_goose = name_targets.into_iter().find(|(_, targets)| targets.contains(&"rx".to_string())).or_missing("module feeding rx")?.0;
 
-        let sources = modules.get(&silly_goose).unwrap().logic.unwrap_conj().keys().cloned().collect::<Vec<_>>();
+        let sources = modules.get(&silly_goose).or_missing("module feeding rx")?.logic.unwrap_conj()?.keys().cloned().collect::<Vec<_>>();
 
         let mut cycles_per = sources.iter().map(|k| (k, vec![])).collect::<HashMap<_, Vec<usize>>>();
 
@@ -212,7 +212,7 @@ impl Day for Day20 {
             i += 1;
         }
 
-        cycles_per.values().map(|v| (v[1] - v[0]) as i64).fold(1, least_common_denominator)
+        Ok(cycles_per.values().map(|v| (v[1] - v[0]) as i64).fold(1, least_common_denominator).into())
 
     }
 }
@@ -222,19 +222,20 @@ impl Day for Day20 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day20;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 825896364);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 20, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day20;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 243566897206981);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 20, 2));
     }
 
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::{collections::HashSet, iter::once};
 
-use crate::{day::Day, get_input_for_day, utils::{Grid, grid::Position}};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, runner, utils::{Grid, SparseGrid, grid::Position}};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -9,13 +9,15 @@ enum Tile {
     Rock
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            'S' => Tile::Start,
-            '.' => Tile::GardenPlot,
-            '#' => Tile::Rock,
-            _ => panic!("Invalid tile")
+            'S' => Ok(Tile::Start),
+            '.' => Ok(Tile::GardenPlot),
+            '#' => Ok(Tile::Rock),
+            _ => Err(format!("Invalid tile: {}", c))
         }
     }
 }
@@ -28,13 +30,13 @@ impl Day for Day21 {
 
     get_input_for_day!(21);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         const STEPS: usize = 64;
 
         // 61051250 - Too low
 
-        let grid = Garden::parse(input);
-        let start_pos = grid.iter().find(|(_, t)| **t == Tile::Start).unwrap().0;
+        let grid = Garden::try_parse(input)?;
+        let start_pos = grid.iter().find(|(_, t)| **t == Tile::Start).or_missing("start tile")?.0;
 
         let mut tracked = HashSet::<Position>::new();
         let mut seen_tiles = HashSet::<Position>::new();
@@ -45,7 +47,7 @@ impl Day for Day21 {
         for _ in 0..STEPS {
             let mut new_tracked = HashSet::<Position>::new();
             for p in tracked.iter() {
-                for (adj, _) in grid.get_direct_adjacents(*p) {
+                for (adj, _) in grid.neighbours(*p) {
                     let tile = grid.get(adj).unwrap();
                     if tile == &Tile::GardenPlot || tile == &Tile::Start {
                         if seen_tiles.contains(&adj) {
@@ -59,64 +61,64 @@ impl Day for Day21 {
             tracked = new_tracked;
         }
         
-        seen_tiles.iter().filter(|p| {
+        Ok(seen_tiles.iter().filter(|p| {
             let dist = ((p.0 as i64) - (start_pos.0 as i64)) + ((p.1 as i64) - (start_pos.1 as i64));
             let dist = dist.abs();
-            if STEPS % 2 == 0 {
+            if STEPS.is_multiple_of(2) {
                 dist % 2 == 0
             } else {
                 dist % 2 == 1
             }
-        }).count() as i64
+        }).count().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
 
         const DELTA: usize = 3;
 
-        let grid = Garden::parse(input);
-        let start_pos = grid.iter().find(|(_, t)| **t == Tile::Start).unwrap().0;
+        let grid = Garden::try_parse(input)?;
+        let start_pos = grid.iter().find(|(_, t)| **t == Tile::Start).or_missing("start tile")?.0;
 
         let half_step = start_pos.0;
         let steps = grid.width * DELTA + half_step;
 
+        let start = (start_pos.0 as isize, start_pos.1 as isize);
         let mut tracked = HashSet::<(isize, isize)>::new();
-        let mut seen_tiles = HashSet::<(isize, isize)>::new();
+        // The step each plot was first reached on, it spreads well past the garden
+        let mut reached = SparseGrid::new();
 
-        tracked.insert((start_pos.0 as isize, start_pos.1 as isize));
-        seen_tiles.insert((start_pos.0 as isize, start_pos.1 as isize));
+        tracked.insert(start);
+        reached.insert(start, 0);
 
         let mut coeffs = vec![];
 
         for i in 1..=steps {
+            runner::check_cancelled()?;
             let mut new_tracked = HashSet::<(isize, isize)>::new();
             for p in tracked.iter() {
-                for (adj, _) in grid.get_direct_adjacents_wrapping(*p) {
+                for (adj, _) in reached.neighbours(*p) {
                     let tile = grid.infinite_get(adj);
                     if tile == &Tile::GardenPlot || tile == &Tile::Start {
-                        if seen_tiles.contains(&adj) {
+                        if reached.contains(adj) {
                             continue;
                         }
                         new_tracked.insert(adj);
-                        seen_tiles.insert(adj);
+                        reached.insert(adj, i);
                     }
                 }
             }
             if i % grid.width == half_step {
-                let seen = seen_tiles.iter().filter(|p| {
-                    let dist = ((p.0 as i64) - (start_pos.0 as i64)) + ((p.1 as i64) - (start_pos.1 as i64));
-                    let dist = dist.abs();
-                    if i % 2 == 0 {
-                        dist % 2 == 0
-                    } else {
-                        dist % 2 == 1
-                    }
-                }).count() as i64;
+                // Plots reached on an earlier step of the same parity can be stepped back onto
+                let seen = reached.iter().filter(|(_, step)| *step % 2 == i % 2).count() as i64;
                 coeffs.push(seen);
             }
             tracked = new_tracked;
         }
         
+        if coeffs.len() < 3 {
+            return Err(SolveError::new("Garden is too small to extrapolate from"));
+        }
+
         let diffs = once(coeffs[0]).chain(coeffs.windows(2).map(|w| w[1] - w[0])).collect::<Vec<_>>();
 
         let (a, b, c) = (diffs[0], diffs[1], diffs[2]);
@@ -125,7 +127,7 @@ impl Day for Day21 {
 
         let n = ((ACTUAL_STEPS - half_step) / grid.width) as i64;
 
-        a + (b * n) + (n * (n - 1) / 2) * (c - b)
+        Ok((a + (b * n) + (n * (n - 1) / 2) * (c - b)).into())
     }
     
 }
@@ -135,19 +137,20 @@ impl Day for Day21 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day21;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 3776);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 21, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day21;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 625587097150084);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 21, 2));
     }
 
 }
\ No newline at end of file
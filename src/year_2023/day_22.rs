@@ -1,8 +1,8 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::{parse_lines, parse_number, SparseGrid}};
 
-type Pos3D = (i64, i64, i64);
+type Pos3D = (isize, isize, isize);
 
 struct Brick {
     start_pos: Pos3D,
@@ -11,19 +11,22 @@ struct Brick {
 
 impl Brick {
 
-    fn parse_pos(input: &str) -> Pos3D {
+    fn parse_pos(input: &str) -> SolveResult<Pos3D> {
         let mut parts = input.split(',');
-        let x = parts.next().unwrap().parse::<i64>().unwrap();
-        let y = parts.next().unwrap().parse::<i64>().unwrap();
-        let z = parts.next().unwrap().parse::<i64>().unwrap();
-        (x, y, z)
+        let x = parse_number(parts.next().or_missing("x")?)?;
+        let y = parse_number(parts.next().or_missing("y")?)?;
+        let z = parse_number(parts.next().or_missing("z")?)?;
+        Ok((x, y, z))
     }
 
-    pub fn parse(input: &str) -> Self {
+    pub fn parse(input: &str) -> SolveResult<Self> {
         let mut split = input.split('~');
-        let start_pos = Self::parse_pos(split.next().unwrap());
-        let end_pos = Self::parse_pos(split.next().unwrap());
-        Self { start_pos, end_pos }
+        let start_pos = Self::parse_pos(split.next().or_missing("start position")?)?;
+        let end_pos = Self::parse_pos(split.next().or_missing("end position")?)?;
+        if start_pos.0 > end_pos.0 || start_pos.1 > end_pos.1 || start_pos.2 > end_pos.2 {
+            return Err(SolveError::at_token(input, "Brick ends before it starts"));
+        }
+        Ok(Self { start_pos, end_pos })
     }
 
     pub fn get_parts(&self) -> Vec<Pos3D> {
@@ -65,12 +68,12 @@ impl Day for Day22 {
 
     get_input_for_day!(22);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let bricks = input.lines().map(Brick::parse).collect::<Vec<_>>();
+    fn part_1(&self, input: &str) -> SolveResult {
+        let bricks = parse_lines(input, Brick::parse)?;
 
-        let mut highest: HashMap<(i64, i64), i64> = HashMap::new();
+        let mut highest: SparseGrid<isize> = SparseGrid::new();
 
-        let highest_z = bricks.iter().map(|b| b.end_pos.2.max(b.start_pos.2)).max().unwrap();
+        let highest_z = bricks.iter().map(|b| b.end_pos.2.max(b.start_pos.2)).max().or_missing("bricks")?;
 
         let mut new_bricks = vec![];
 
@@ -84,7 +87,7 @@ impl Day for Day22 {
                     continue;
                 }
 
-                let closest = brick.iter().map(|p| (p, highest.get(&(p.0, p.1)).copied().unwrap_or(0))).max_by(|a, b| {
+                let closest = brick.iter().map(|p| (p, highest.get((p.0, p.1)).copied().unwrap_or(0))).max_by(|a, b| {
                     a.1.cmp(&b.1).then(a.0.2.cmp(&b.0.2).reverse())
                 }).unwrap();
 
@@ -93,7 +96,7 @@ impl Day for Day22 {
                 let new_brick = brick.iter().map(|p| (p.0, p.1, p.2 - delta)).collect::<Vec<_>>();
 
                 for p in new_brick.iter() {
-                    if let Some(h) = highest.get_mut(&(p.0, p.1)) {
+                    if let Some(h) = highest.get_mut((p.0, p.1)) {
                         if *h < p.2 {
                             *h = p.2;
                         }
@@ -121,17 +124,17 @@ impl Day for Day22 {
             }
         }
 
-        supports.iter().enumerate().filter(|(i, _)| {
+        Ok(supports.iter().enumerate().filter(|(i, _)| {
             supports.iter().all(|s| !s.contains(i) || s.len() > 1)
-        }).count() as i64
+        }).count().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let bricks = input.lines().map(Brick::parse).collect::<Vec<_>>();
+    fn part_2(&self, input: &str) -> SolveResult {
+        let bricks = parse_lines(input, Brick::parse)?;
 
-        let mut highest: HashMap<(i64, i64), i64> = HashMap::new();
+        let mut highest: SparseGrid<isize> = SparseGrid::new();
 
-        let highest_z = bricks.iter().map(|b| b.end_pos.2.max(b.start_pos.2)).max().unwrap();
+        let highest_z = bricks.iter().map(|b| b.end_pos.2.max(b.start_pos.2)).max().or_missing("bricks")?;
 
         let mut new_bricks = vec![];
 
@@ -145,7 +148,7 @@ impl Day for Day22 {
                     continue;
                 }
 
-                let closest = brick.iter().map(|p| (p, highest.get(&(p.0, p.1)).copied().unwrap_or(0))).max_by(|a, b| {
+                let closest = brick.iter().map(|p| (p, highest.get((p.0, p.1)).copied().unwrap_or(0))).max_by(|a, b| {
                     a.1.cmp(&b.1).then(a.0.2.cmp(&b.0.2).reverse())
                 }).unwrap();
 
@@ -154,7 +157,7 @@ impl Day for Day22 {
                 let new_brick = brick.iter().map(|p| (p.0, p.1, p.2 - delta)).collect::<Vec<_>>();
 
                 for p in new_brick.iter() {
-                    if let Some(h) = highest.get_mut(&(p.0, p.1)) {
+                    if let Some(h) = highest.get_mut((p.0, p.1)) {
                         if *h < p.2 {
                             *h = p.2;
                         }
@@ -192,9 +195,13 @@ impl Day for Day22 {
             total += get_branch_size(i, &mut v, &supported_by, &supports);
         }
 
-        total as i64
+        Ok(total.into())
     }
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, Brick::parse).map(drop))
+    }
 }
 
 
@@ -202,19 +209,20 @@ impl Day for Day22 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day22;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 527);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 22, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day22;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 100376);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 22, 2));
     }
 
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::{HashSet, HashMap, BinaryHeap};
 
-use crate::{day::Day, get_input_for_day, utils::{Direction, grid::{GridPointer, Position}}};
+use crate::{day::Day, error::{OrMissing, SolveResult}, get_input_for_day, runner, utils::{Direction, ToTile, grid::{GridPointer, Position}, render::Colour}};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -21,25 +21,48 @@ impl Tile {
 
 }
 
-impl From<char> for Tile {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            '.' => Tile::Trail,
-            '#' => Tile::Forest,
+            '.' => Ok(Tile::Trail),
+            '#' => Ok(Tile::Forest),
             _ => {
                 let dir = match c {
                     '^' => Direction::North,
                     'v' => Direction::South,
                     '<' => Direction::West,
                     '>' => Direction::East,
-                    _ => panic!("Invalid character")
+                    _ => return Err(format!("Invalid character: {}", c))
                 };
-                Tile::Slope(dir)
+                Ok(Tile::Slope(dir))
             }
         }
     }
 }
 
+impl ToTile for Tile {
+    fn to_tile(&self) -> char {
+        match self {
+            Tile::Trail => '.',
+            Tile::Forest => '#',
+            Tile::Slope(Direction::North) => '^',
+            Tile::Slope(Direction::South) => 'v',
+            Tile::Slope(Direction::West) => '<',
+            Tile::Slope(Direction::East) => '>',
+        }
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        match self {
+            Tile::Trail => None,
+            Tile::Forest => Some(Colour::Green),
+            Tile::Slope(_) => Some(Colour::Yellow),
+        }
+    }
+}
+
 type Grid = crate::utils::grid::Grid<Tile>;
 
 fn get_to_other_intersection(grid: &Grid, pos: Position, dir: Direction) -> (Position, usize) {
@@ -47,9 +70,7 @@ fn get_to_other_intersection(grid: &Grid, pos: Position, dir: Direction) -> (Pos
     let mut steps = 0;
 
     loop {
-        let adjacents = grid.get_direct_adjacents(p.pos);
-
-        let mut valid_adjacents = adjacents.into_iter().filter(|(pos, d)| {
+        let mut valid_adjacents = grid.neighbours(p.pos).filter(|(pos, d)| {
             d.opposite() != p.dir && grid.get(*pos).unwrap().can_go(*d, true)
         });
 
@@ -67,8 +88,7 @@ fn get_to_other_intersection(grid: &Grid, pos: Position, dir: Direction) -> (Pos
 fn get_intersections(s: Position, e: Position, grid: &Grid) -> HashMap<Position, Vec<(Position, usize)>> {
     grid.iter().filter_map(|(p, t)| {
         if *t == Tile::Trail {
-            let adjacents = grid.get_direct_adjacents(p);
-            let valid_adjacents = adjacents.into_iter().filter(|(ap, d)| {
+            let valid_adjacents = grid.neighbours(p).filter(|(ap, d)| {
                 grid.get(*ap).unwrap().can_go(*d, true)
             }).collect::<Vec<_>>();
             if s == p || e == p || valid_adjacents.len() > 2 {
@@ -93,7 +113,7 @@ impl Ord for Node {
 
 impl PartialOrd for Node {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.2.cmp(&other.2))
+        Some(self.cmp(other))
     } 
 }
 
@@ -111,18 +131,19 @@ impl Day for Day23 {
 
     get_input_for_day!(23);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let grid = Grid::parse(input);
+    fn part_1(&self, input: &str) -> SolveResult {
+        let grid = Grid::try_parse(input)?;
 
-        let start_pos = grid.iter().find(|(_, t)|  **t == Tile::Trail).unwrap().0;
-        let target_pos = grid.iter_rev().rev().find(|(_, t)|  **t == Tile::Trail).unwrap().0;
+        let start_pos = grid.iter().find(|(_, t)|  **t == Tile::Trail).or_missing("trail")?.0;
+        let target_pos = grid.iter_rev().rev().find(|(_, t)|  **t == Tile::Trail).or_missing("trail")?.0;
 
         let mut frontier = vec![(GridPointer::new(start_pos, Direction::South), 0)];
 
         let mut largest = None;
 
         while let Some((p, steps)) = frontier.pop() {
-            
+            runner::check_cancelled()?;
+
             if p.pos == target_pos {
                 if largest.map(|l| steps > l).unwrap_or(true) {
                     largest = Some(steps);
@@ -130,9 +151,7 @@ impl Day for Day23 {
                 continue;
             }
 
-            let adjacents = grid.get_direct_adjacents(p.pos);
-
-            let valid_adjacents = adjacents.into_iter().filter(|(pos, d)| {
+            let valid_adjacents = grid.neighbours(p.pos).filter(|(pos, d)| {
                 d.opposite() != p.dir && grid.get(*pos).unwrap().can_go(*d, false)
             });
 
@@ -144,15 +163,15 @@ impl Day for Day23 {
             }
         }
 
-        largest.unwrap() as i64
+        Ok(largest.or_missing("path to the end")?.into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
 
-        let grid = Grid::parse(input);
+        let grid = Grid::try_parse(input)?;
 
-        let start_pos = grid.iter().find(|(_, t)|  **t == Tile::Trail).unwrap().0;
-        let target_pos = grid.iter_rev().rev().find(|(_, t)|  **t == Tile::Trail).unwrap().0;
+        let start_pos = grid.iter().find(|(_, t)|  **t == Tile::Trail).or_missing("trail")?.0;
+        let target_pos = grid.iter_rev().rev().find(|(_, t)|  **t == Tile::Trail).or_missing("trail")?.0;
 
         let intersections = get_intersections(start_pos, target_pos, &grid);
         
@@ -161,6 +180,8 @@ impl Day for Day23 {
         let mut largest = None;
 
         while let Some(Node(p, mut seen, steps)) = frontier.pop() {
+            runner::check_cancelled()?;
+
             if p == target_pos {
                 if largest.map(|l| steps > l).unwrap_or(true) {
                     largest = Some(steps);
@@ -170,7 +191,7 @@ impl Day for Day23 {
 
             seen.insert(p);
 
-            let edges = intersections.get(&p).unwrap();
+            let edges = intersections.get(&p).or_missing("intersection")?;
 
             for (pos, steps_to) in edges {
                 if !seen.contains(pos) {
@@ -181,9 +202,13 @@ impl Day for Day23 {
             }
         }
 
-        largest.unwrap() as i64
+        Ok(largest.or_missing("path to the end")?.into())
     }
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(Grid::try_parse(input).map(drop))
+    }
 }
 
 
@@ -191,12 +216,13 @@ impl Day for Day23 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day23;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 2170);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 23, 1));
     }
 
     #[test]
@@ -204,8 +230,8 @@ mod tests {
         // Skipping bc this is an NP-Hard problem and takes too long to run
 
         // let day = Day23;
-        // let input = day.get_input();
-        // assert_eq!(day.part_2(&input), 6502);
+        // let input = day.get_input().unwrap();
+        // assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 23, 2));
     }
 
 }
\ No newline at end of file
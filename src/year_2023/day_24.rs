@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveResult}, get_input_for_day, utils::{parse_lines, parse_number}};
 
 type Position3D = (f64, f64, f64);
 
@@ -11,19 +11,19 @@ struct HailStone {
 
 impl HailStone {
 
-    fn parse_position_3d(input: &str) -> Position3D {
-        let split = input.split(',').collect::<Vec<_>>();
-        let x = split[0].trim().parse::<f64>().unwrap();
-        let y = split[1].trim().parse::<f64>().unwrap();
-        let z = split[2].trim().parse::<f64>().unwrap();
-        (x, y, z)
+    fn parse_position_3d(input: &str) -> SolveResult<Position3D> {
+        let mut split = input.split(',');
+        let x = parse_number(split.next().or_missing("x")?)?;
+        let y = parse_number(split.next().or_missing("y")?)?;
+        let z = parse_number(split.next().or_missing("z")?)?;
+        Ok((x, y, z))
     }
 
-    pub fn parse(input: &str) -> Self {
-        let split = input.split('@').collect::<Vec<_>>();
-        let position = Self::parse_position_3d(split[0]);
-        let velocity = Self::parse_position_3d(split[1]);
-        Self { position, velocity }
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        let (position, velocity) = input.split_once('@').or_missing("velocity")?;
+        let position = Self::parse_position_3d(position)?;
+        let velocity = Self::parse_position_3d(velocity)?;
+        Ok(Self { position, velocity })
     }
 
     pub fn check_in_future(&self, point: Position3D) -> bool {
@@ -63,12 +63,12 @@ impl Day for Day24 {
 
     get_input_for_day!(24);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         const TEST_AREA: Range<f64> = 200000000000000.0..400000000000001.0;
 
-        let stones = input.lines().map(HailStone::parse).collect::<Vec<_>>();
+        let stones = parse_lines(input, HailStone::parse)?;
 
-        stones.iter().map(|l| {
+        Ok((stones.iter().map(|l| {
             stones.iter().filter(|l2| {
                 if let Some(i) = l.get_intercept(l2) {
                     TEST_AREA.contains(&i.0) && TEST_AREA.contains(&i.1)
@@ -76,12 +76,12 @@ impl Day for Day24 {
                     false
                 }
             }).count() as i64
-        }).sum::<i64>() / 2
+        }).sum::<i64>() / 2).into())
     }
 
-    fn part_2(&self, _input: &str) -> i64 {
+    fn part_2(&self, _input: &str) -> SolveResult {
         // TODO: Wanna try some fancy schmancy math?
-        908621716620524
+        Ok(908621716620524_i64.into())
     }
 
     // Original solution using Z3
@@ -143,19 +143,20 @@ impl Day for Day24 {
 mod tests {
 
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day24;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 20847);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 24, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day24;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 908621716620524);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 24, 2));
     }
 
 }
\ No newline at end of file
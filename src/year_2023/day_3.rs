@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{day::Day, get_input_for_day, utils::Grid};
+use crate::{day::Day, error::SolveResult, get_input_for_day, utils::{Grid, dir::ExpandedDirection}};
 
 pub struct Day3;
 
@@ -8,17 +8,11 @@ const EMPTY_CHAR: char = '.';
 
 type Manual = Grid<char>;
 
-fn str_to_2d_map(input: &str) -> Manual {
-    let mut map = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-
-    for line in map.iter_mut() {
-        line.push(EMPTY_CHAR); // Pad with a . at the end so numbers at the ends get processed
-    }
-
-    Manual::new(map)
+fn str_to_2d_map(input: &str) -> SolveResult<Manual> {
+    let mut map = Manual::try_parse(input)?;
+    // Pad with a . at the end so numbers at the ends get processed
+    map.resize(map.width + 1, map.height, EMPTY_CHAR);
+    Ok(map)
 }
 
 fn check_char(char: char) -> bool {
@@ -28,49 +22,39 @@ fn check_char(char: char) -> bool {
 type AdjacentCharMap = Vec<((usize, usize), char)>;
 
 fn get_adjacent_chars(input_map: &Manual, row: usize, starting_index: usize, ending_index: usize) -> AdjacentCharMap {
-    let mut chars: AdjacentCharMap = vec![];
-
-    let start_minus_one = starting_index.saturating_sub(1);
-
-    if let Some(next_on_this_level) = input_map.get_row(row).and_then(|r| r.get(start_minus_one).copied()) {
-        chars.push(((row, start_minus_one), *next_on_this_level));
+    let first = (starting_index, row);
+    let last = (ending_index, row);
+
+    // Both ends first, then the rows above and below from left to right
+    let mut border = vec![(first, ExpandedDirection::West), (last, ExpandedDirection::East)];
+    border.extend([(first, ExpandedDirection::NorthWest), (first, ExpandedDirection::SouthWest)]);
+    for col in starting_index..=ending_index {
+        border.extend([((col, row), ExpandedDirection::North), ((col, row), ExpandedDirection::South)]);
     }
+    border.extend([(last, ExpandedDirection::NorthEast), (last, ExpandedDirection::SouthEast)]);
 
-    if let Some(prev_on_this_level) = input_map.get_row(row).and_then(|r| r.get(ending_index + 1).copied()) {
-        chars.push(((row, ending_index + 1), *prev_on_this_level));
-    }
-
-    let above = if row == 0 { None } else { input_map.get_row(row - 1) };
-    let below = input_map.get_row(row + 1);
-
-    for current_col in start_minus_one..ending_index + 2 {
-        if let Some(above_row) = above.as_ref().and_then(|r| r.get(current_col).copied()).copied() {
-            chars.push(((row - 1, current_col), above_row));
-        }
-        if let Some(below_row) = below.as_ref().and_then(|r| r.get(current_col).copied()).copied(){
-            chars.push(((row + 1, current_col), below_row));
-        }
-    }
-
-    chars
+    border.into_iter().filter_map(|(pos, dir)| {
+        let (x, y) = input_map.get_next_pos(pos, dir)?;
+        Some(((y, x), *input_map.get((x, y))?))
+    }).collect()
 }
 
 impl Day for Day3 {
 
     get_input_for_day!(3);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         
-        let input_map = str_to_2d_map(input);
+        let input_map = str_to_2d_map(input)?;
 
-        input_map.iter_rows().enumerate().map(|(x, r)| {
+        Ok(input_map.rows().enumerate().map(|(x, r)| {
             let mut start_index = 0;
             let mut current_num = String::new();
             let mut total = 0;
 
             for (y, char) in r.iter().enumerate() {
                 if char.is_ascii_digit() {
-                    current_num.push(**char);
+                    current_num.push(*char);
                     if current_num.len() == 1 {
                         start_index = y;
                     }
@@ -98,23 +82,23 @@ impl Day for Day3 {
             }
 
             total
-        }).sum::<i64>()
+        }).sum::<i64>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
         
-        let input_map = str_to_2d_map(input);
+        let input_map = str_to_2d_map(input)?;
     
         let mut stars: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
 
-        for (i, line) in input_map.iter_rows().enumerate() {
+        for (i, line) in input_map.rows().enumerate() {
 
             let mut start_index = 0;
             let mut current_num = String::new();
 
             for (j, char) in line.iter().enumerate() {
                 if char.is_ascii_digit() {
-                    current_num.push(**char);
+                    current_num.push(*char);
                     if current_num.len() == 1 {
                         start_index = j;
                     }
@@ -157,20 +141,25 @@ impl Day for Day3 {
                 }
             }).sum();
 
-        total as i64
+        Ok(total.into())
     }
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(str_to_2d_map(input).map(drop))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_get_adjacent_chars() {
         let input = "1.2.3.4.5.6...\n7.8.9.10.11.12\n13.14.15.16.17";
 
-        let input_map = str_to_2d_map(input);
+        let input_map = str_to_2d_map(input).unwrap();
 
         let target = (1, 6, 7);
 
@@ -193,21 +182,27 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_malformed_schematic() {
+        let err = Day3.part_1("467..114..\n...*......\n..35..633").unwrap_err();
+        assert_eq!(err.line, Some(3));
+    }
+
     #[test]
     fn test_part_1() {
         let day = Day3;
-        let input = day.get_input();
-        let expected = 550064;
-        let actual = day.part_1(input);
+        let input = day.get_input().unwrap();
+        let expected = answers::expected(2023, 3, 1);
+        let actual = day.part_1(&input).unwrap();
         assert_eq!(expected, actual);
     }
 
     #[test]
     fn test_part_2() {
         let day = Day3;
-        let input = day.get_input();
-        let expected = 85010461;
-        let actual = day.part_2(input);
+        let input = day.get_input().unwrap();
+        let expected = answers::expected(2023, 3, 2);
+        let actual = day.part_2(&input).unwrap();
         assert_eq!(expected, actual);
     }
 
@@ -1,4 +1,4 @@
-use crate::{day::Day, get_input_for_day, utils::parse_padded_numbers};
+use crate::{day::Day, error::{OrMissing, SolveResult}, get_input_for_day, utils::{parse_lines, parse_padded_numbers}};
 
 pub struct Day4;
 
@@ -10,16 +10,15 @@ struct Card {
 
 impl Card {
 
-    pub fn parse(input: &str) -> Self {
-        let data = input.split(':').nth(1).unwrap();
-        let data = data.trim().replace("  ", " ");
-        let s = data.split('|');
-        let winning = parse_padded_numbers(s.clone().nth(0).unwrap());
-        let numbers = parse_padded_numbers(s.clone().nth(1).unwrap());
-        Self {
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        let data = input.split(':').nth(1).or_missing("card numbers")?;
+        let mut s = data.split('|');
+        let winning = parse_padded_numbers(s.next().or_missing("winning numbers")?)?;
+        let numbers = parse_padded_numbers(s.next().or_missing("numbers after '|'")?)?;
+        Ok(Self {
             winning,
             numbers,
-        }
+        })
     }
 
     pub fn get_amount_matching(&self) -> u32 {
@@ -41,16 +40,14 @@ impl Day for Day4 {
 
     get_input_for_day!(4);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let lines = input.split('\n');
-        let cards = lines.map(Card::parse).collect::<Vec<Card>>();
+    fn part_1(&self, input: &str) -> SolveResult {
+        let cards = parse_lines(input, Card::parse)?;
 
-        cards.into_iter().map(|x| x.get_score()).sum::<u32>() as i64
+        Ok(cards.into_iter().map(|x| x.get_score()).sum::<u32>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let lines = input.split('\n');
-        let cards = lines.map(Card::parse).collect::<Vec<Card>>();
+    fn part_2(&self, input: &str) -> SolveResult {
+        let cards = parse_lines(input, Card::parse)?;
 
         let mut amounts = vec![1;cards.len()];
 
@@ -67,20 +64,25 @@ impl Day for Day4 {
             }
         }
 
-        amounts.into_iter().sum::<u32>() as i64
+        Ok(amounts.into_iter().sum::<u32>().into())
     }
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, Card::parse).map(drop))
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_parse() {
         let input = "Card 1: 1 2 3 4 5 | 6 7 8 9 10";
-        let card = Card::parse(input);
+        let card = Card::parse(input).unwrap();
         assert_eq!(card.winning, vec![1, 2, 3, 4, 5]);
         assert_eq!(card.numbers, vec![6, 7, 8, 9, 10]);
     }
@@ -88,22 +90,22 @@ mod tests {
     #[test]
     fn test_get_score() {
         let input = "Card 1: 1 2 3 4 5 | 1 1 4 9 10";
-        let card = Card::parse(input);
+        let card = Card::parse(input).unwrap();
         assert_eq!(card.get_score(), 4);
     }
 
     #[test]
     fn test_part_1() {
         let day = Day4 {};
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 23750);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 4, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day4 {};
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 13261850);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 4, 2));
     }
 
 }
\ No newline at end of file
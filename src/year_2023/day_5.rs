@@ -1,6 +1,6 @@
 use std::ops::Range;
 
-use crate::{day::Day, get_input_for_day, utils::parse_padded_numbers};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::parse_padded_numbers};
 
 
 pub struct Day5;
@@ -13,15 +13,18 @@ struct MapRow {
 
 impl MapRow {
 
-    pub fn parse(input: &str) -> Self {
-        let nums = parse_padded_numbers(input);
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        let nums = parse_padded_numbers(input)?;
+        if nums.len() != 3 {
+            return Err(SolveError::at_token(input, format!("Expected 3 numbers in a map row, got {}", nums.len())));
+        }
         let dest_start = nums[0];
         let source_start = nums[1];
         let length = nums[2];
-        Self {
+        Ok(Self {
             dest_range: dest_start..dest_start+length,
             source_range: source_start..source_start+length,
-        }
+        })
     }
 
     // Here's a little remnant of my descent into madness:
@@ -70,14 +73,14 @@ struct Map {
 
 impl Map {
 
-    pub fn parse(input: &str) -> Self {
-        let rows = input.lines().map(MapRow::parse).collect();
-        Self {
+    pub fn parse(input: &[&str]) -> SolveResult<Self> {
+        let rows = input.iter().map(|l| MapRow::parse(l)).collect::<SolveResult<_>>()?;
+        Ok(Self {
             rows,
-        }
+        })
     }
 
-    pub fn parse_all(lines: Vec<&str>) -> Vec<Self> {
+    pub fn parse_all(lines: Vec<&str>) -> SolveResult<Vec<Self>> {
         let mut lines = lines;
 
         lines.push("map:");
@@ -90,7 +93,7 @@ impl Map {
 
         for (i, line) in lines.into_iter().skip(1).enumerate() {
             if line.contains("map:") || i == len - 1 {
-                let map = Map::parse(current_map_input.join("\n").as_str());
+                let map = Map::parse(&current_map_input)?;
                 if map.has_rows() {
                     maps.push(map);
                 }
@@ -100,7 +103,7 @@ impl Map {
             }
         }
 
-        maps
+        Ok(maps)
     }
 
     pub fn map(&self, in_num: i64) -> i64 {
@@ -138,30 +141,34 @@ impl Map {
 
 }
 
+fn parse_seeds(lines: &[&str]) -> SolveResult<Vec<i64>> {
+    parse_padded_numbers(lines.first().and_then(|l| l.split(": ").nth(1)).or_missing("seeds")?)
+}
+
 impl Day for Day5 {
 
     get_input_for_day!(5);
 
-    fn part_1(&self, input: &str) -> i64 {
+    fn part_1(&self, input: &str) -> SolveResult {
         let mut lines = input.lines().collect::<Vec<&str>>();
 
         lines.push("map:");
 
-        let seeds = parse_padded_numbers(lines.first().unwrap().split(": ").nth(1).unwrap());
+        let seeds: Vec<i64> = parse_seeds(&lines)?;
 
-        let maps = Map::parse_all(lines);
+        let maps = Map::parse_all(lines)?;
 
         let locations = seeds.iter().map(|s| {
             maps.iter().fold(*s, |num, map| map.map(num))
         }).collect::<Vec<i64>>();
 
-        *locations.iter().min().unwrap() as i64
+        Ok(locations.into_iter().min().or_missing("seeds")?.into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
+    fn part_2(&self, input: &str) -> SolveResult {
         let lines = input.lines().collect::<Vec<&str>>();
 
-        let seeds = parse_padded_numbers(lines.first().unwrap().split(": ").nth(1).unwrap());
+        let seeds = parse_seeds(&lines)?;
 
         let mut ranges = vec![];
         let mut start = 0;
@@ -174,7 +181,7 @@ impl Day for Day5 {
             }
         }
 
-        let maps = Map::parse_all(lines);
+        let maps = Map::parse_all(lines)?;
 
         let mut starts = vec![];
 
@@ -184,7 +191,7 @@ impl Day for Day5 {
             starts.append(&mut _starts);
         }
 
-        *starts.iter().min().unwrap()
+        Ok(starts.into_iter().min().or_missing("seeds")?.into())
     }
 
 }
@@ -192,19 +199,20 @@ impl Day for Day5 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = super::Day5;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 174137457);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 5, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = super::Day5;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 1493866);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 5, 2));
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,93 @@
+use crate::{day::Day, error::{OrMissing, SolveResult}, get_input_for_day, utils::{parse_number, parse_padded_numbers}};
+
+
+pub struct Day6;
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64
+}
+
+impl Race {
+
+    fn get_values(input: &str) -> SolveResult<(&str, &str)> {
+        let mut lines = input.lines();
+        let times = lines.next().and_then(|l| l.split(": ").nth(1)).or_missing("times")?;
+        let distances = lines.next().and_then(|l| l.split(": ").nth(1)).or_missing("distances")?;
+        Ok((times, distances))
+    }
+
+    pub fn parse(input: &str) -> SolveResult<Vec<Self>> {
+        let (times, distances) = Self::get_values(input)?;
+
+        let times = parse_padded_numbers(times)?;
+        let distances = parse_padded_numbers(distances)?;
+
+        Ok(times.into_iter().zip(distances).map(|(time, distance)| Self { time, distance }).collect::<Vec<Self>>())
+    }
+
+    pub fn parse_part_2(input: &str) -> SolveResult<Self> {
+        let (times, distances) = Self::get_values(input)?;
+        let time = parse_number(&times.replace(' ', ""))?;
+        let distance = parse_number(&distances.replace(' ', ""))?;
+
+        Ok(Self { time, distance })
+    }
+
+    // This is my original solution, which I want to redo bc of how easy day 6 was
+    // pub fn ways_to_win(&self) -> u64 {
+    //     let time_range = 1..self.time;
+    //     time_range.filter(|t| {
+    //         let speed = t;
+    //         let distance_traveled = speed * (self.time - t);
+    //         distance_traveled >= self.distance
+    //     }).count() as u64
+    // }
+
+    // New solution using quadratics
+    pub fn ways_to_win(&self) -> u64 {
+        let time = self.time as f64;
+        let distance = self.distance as f64;
+        let rt = (time * time - 4_f64 * distance).sqrt();
+        let lower_bound = ((-time + rt) / -2_f64).ceil();
+        let upper_bound = ((-time - rt) / -2_f64).ceil();
+        (upper_bound - lower_bound) as u64
+    }
+
+}
+
+impl Day for Day6 {
+
+    get_input_for_day!(6);
+
+    fn part_1(&self, input: &str) -> SolveResult {
+        Ok(Race::parse(input)?.into_iter().fold(1_u64, |acc, race| acc * race.ways_to_win()).into())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        Ok(Race::parse_part_2(input)?.ways_to_win().into())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    #[test]
+    fn test_part_1() {
+        let day = Day6;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 6, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = Day6;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 6, 2));
+    }
+
+}
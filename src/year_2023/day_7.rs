@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day, utils::{parse_lines, parse_number}};
 
 #[derive(PartialEq, PartialOrd, Eq, Ord, Debug)]
 enum HandType {
@@ -27,16 +27,29 @@ impl Hand {
     const VALS_JOKERS: &'static str = "J23456789TJQKA";
     const VALS: &'static str = "23456789TJQKA";
 
-    pub fn parse(input: &str, consider_jokers: bool) -> Self {
+    pub fn parse(input: &str, consider_jokers: bool) -> SolveResult<Self> {
         let mut split = input.split(' ');
-        let values = split.next().unwrap().chars().collect::<Vec<char>>();
-        let bid = split.next().unwrap().parse::<u64>().unwrap();
+        let values = split.next().or_missing("cards")?.chars().collect::<Vec<char>>();
+        let bid = parse_number(split.next().or_missing("bid")?)?;
 
-        Self {
+        Ok(Self {
             values,
             consider_jokers,
             bid
+        })
+    }
+
+    /// Like [`Hand::parse`], but only takes hands of five known cards, which
+    /// is what the hand types and card values count on.
+    pub fn try_parse(input: &str, consider_jokers: bool) -> SolveResult<Self> {
+        let cards = input.split(' ').next().or_missing("cards")?;
+        if let Some((i, card)) = cards.char_indices().find(|(_, c)| !Self::VALS.contains(*c)) {
+            return Err(SolveError::at_token(&cards[i..i + card.len_utf8()], format!("Unknown card {:?}", card)));
+        }
+        if cards.len() != 5 {
+            return Err(SolveError::at_token(cards, format!("Expected 5 cards, found {}", cards.len())));
         }
+        Self::parse(input, consider_jokers)
     }
 
     fn determine_type(&self) -> HandType {
@@ -186,9 +199,7 @@ impl PartialEq for Hand {
     }
 }
 
-impl Eq for Hand {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl Eq for Hand {}
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -210,16 +221,20 @@ pub struct Day7;
 impl Day for Day7 {
     get_input_for_day!(7);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let mut hands = input.lines().map(|l| Hand::parse(l, false)).collect::<Vec<Hand>>();
+    fn part_1(&self, input: &str) -> SolveResult {
+        let mut hands = parse_lines(input, |l| Hand::try_parse(l, false))?;
         hands.sort();
-        hands.into_iter().enumerate().map(|(i, h)| h.bid * ((i as u64) + 1) ).sum::<u64>() as i64
+        Ok(hands.into_iter().enumerate().map(|(i, h)| h.bid * ((i as u64) + 1) ).sum::<u64>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let mut hands = input.lines().map(|l| Hand::parse(l, true)).collect::<Vec<Hand>>();
+    fn part_2(&self, input: &str) -> SolveResult {
+        let mut hands = parse_lines(input, |l| Hand::try_parse(l, true))?;
         hands.sort();
-        hands.into_iter().enumerate().map(|(i, h)| h.bid * ((i as u64) + 1) ).sum::<u64>() as i64
+        Ok(hands.into_iter().enumerate().map(|(i, h)| h.bid * ((i as u64) + 1) ).sum::<u64>().into())
+    }
+
+    fn parse(&self, part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, |l| Hand::try_parse(l, part == 2)).map(drop))
     }
 }
 
@@ -227,6 +242,7 @@ impl Day for Day7 {
 mod tests {
 
     use super::*;
+    use crate::{answers, runner};
 
     #[test]
     fn test_get_card_type() {
@@ -252,24 +268,32 @@ mod tests {
 
         for (input, expected) in inputs {
             println!("Testing: {}", input);
-            let hand = Hand::parse(input, true);
+            let hand = Hand::parse(input, true).unwrap();
             assert_eq!(hand.determine_type(), expected);
         }
 
     }
 
+    #[test]
+    fn test_malformed_hands() {
+        let err = runner::solve(2023, 7, 1, &Day7, "32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(4)));
+        let err = runner::solve(2023, 7, 2, &Day7, "32T3K 765\nKK67 28").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(1)));
+    }
+
     #[test]
     fn test_part_1() {
         let day = Day7;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 249638405);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 7, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day7;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 249776650);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 7, 2));
     }
 
 }
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::cmp::{max, min};
+
+use crate::{day::Day, error::{OrMissing, SolveError, SolveResult}, get_input_for_day};
+
+
+pub struct Day8;
+
+struct Node {
+    pub id: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+
+    pub fn parse(input: &str) -> SolveResult<Self> {
+        let mut split = input.split(" = ");
+
+        let id = split.next().or_missing("node id")?;
+
+        let (left, right) = split.next().and_then(|c| c.split_once(", ")).or_missing("node children")?;
+
+        let left = left.replace('(', "");
+        let right = right.replace(')', "");
+
+        Ok(Self {
+            id: id.to_string(),
+            left,
+            right
+        })
+    }
+
+    pub fn get_distance(&self, condition: impl Fn(&str) -> bool, directions: &[char], nodes: &HashMap<String, Node>) -> SolveResult<i64> {
+        let mut current = self.id.clone();
+
+        let dir_len = directions.len();
+        let mut dir_pointer = 0;
+
+        while !condition(&current) {
+            let node = nodes.get(&current).ok_or_else(|| SolveError::new(format!("Unknown node {}", current)))?;
+
+            let dir = directions[dir_pointer % dir_len]; 
+
+            if dir == 'L' {
+                current = node.left.clone();
+            } else {
+                current = node.right.clone();
+            }   
+
+            dir_pointer += 1;
+        }
+
+        Ok(dir_pointer as i64)
+    }
+
+}
+
+fn greatest_common_denominator(a: i64, b: i64) -> i64 {
+    let mut max = max(a, b);
+    let mut min = min(a, b);
+
+    while min != 0 {
+        let hold = min;
+        min = max % min;
+        max = hold;
+    }
+
+    max
+}
+
+fn least_common_denominator(a: i64, b: i64) -> i64 {
+    (a * b) / greatest_common_denominator(a, b)
+}
+
+fn parse_network(input: &str) -> SolveResult<(Vec<char>, HashMap<String, Node>)> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let directions = lines.first().or_missing("directions")?.chars().collect::<Vec<char>>();
+    if directions.is_empty() {
+        return Err(SolveError::new("No directions given").on_line(0));
+    }
+    let nodes = lines.iter().enumerate().skip(2).map(|(i, l)| Node::parse(l).map_err(|e| e.on_line(i))).collect::<SolveResult<Vec<_>>>()?;
+    Ok((directions, nodes.into_iter().map(|n| (n.id.clone(), n)).collect()))
+}
+
+impl Day for Day8 {
+
+    get_input_for_day!(8);
+
+    fn part_1(&self, input: &str) -> SolveResult {
+        let (directions, nodes) = parse_network(input)?;
+        let start = nodes.get("AAA").or_missing("node AAA")?;
+
+        Ok(start.get_distance(|s| s == "ZZZ", &directions, &nodes)?.into())
+    }
+
+    fn part_2(&self, input: &str) -> SolveResult {
+        let (directions, nodes) = parse_network(input)?;
+        let distances_to_z = nodes.values().filter(|n| n.id.ends_with('A')).map(|n| {
+            n.get_distance(|s| s.ends_with('Z'), &directions, &nodes)
+        }).collect::<SolveResult<Vec<i64>>>()?;
+
+        Ok(distances_to_z.into_iter().fold(1, least_common_denominator).into())
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    #[test]
+    fn test_part_1() {
+        let day = Day8;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 8, 1));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let day = Day8;
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 8, 2));
+    }
+}
\ No newline at end of file
@@ -1,17 +1,14 @@
-use crate::{day::Day, get_input_for_day};
+use crate::{day::Day, error::SolveResult, get_input_for_day, utils::{parse_lines, parse_padded_numbers}};
 
 struct History {
     nums: Vec<i64>,
 }
 
 impl History {
-    fn parse(input: &str) -> Self {
-        Self {
-            nums: input
-                .split_whitespace()
-                .map(|s| s.parse::<i64>().unwrap())
-                .collect(),
-        }
+    fn parse(input: &str) -> SolveResult<Self> {
+        Ok(Self {
+            nums: parse_padded_numbers(input)?,
+        })
     }
 
     fn _get_pyramid(ins: &[i64]) -> Vec<Vec<i64>> {
@@ -49,35 +46,40 @@ impl Day for Day9 {
 
     get_input_for_day!(9);
 
-    fn part_1(&self, input: &str) -> i64 {
-        let histories = input.lines().map(History::parse).collect::<Vec<_>>();
+    fn part_1(&self, input: &str) -> SolveResult {
+        let histories = parse_lines(input, History::parse)?;
 
-        histories.iter().map(|h| h.calc_next()).sum()
+        Ok(histories.iter().map(|h| h.calc_next()).sum::<i64>().into())
     }
 
-    fn part_2(&self, input: &str) -> i64 {
-        let histories = input.lines().map(History::parse).collect::<Vec<_>>();
+    fn part_2(&self, input: &str) -> SolveResult {
+        let histories = parse_lines(input, History::parse)?;
 
-        histories.iter().map(|h| h.calc_prev()).sum()
+        Ok(histories.iter().map(|h| h.calc_prev()).sum::<i64>().into())
     }
 
+
+    fn parse(&self, _part: u32, input: &str) -> Option<SolveResult<()>> {
+        Some(parse_lines(input, History::parse).map(drop))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_part_1() {
         let day = Day9;
-        let input = day.get_input();
-        assert_eq!(day.part_1(input), 1901217887);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_1(&input).unwrap(), answers::expected(2023, 9, 1));
     }
 
     #[test]
     fn test_part_2() {
         let day = Day9;
-        let input = day.get_input();
-        assert_eq!(day.part_2(input), 905);
+        let input = day.get_input().unwrap();
+        assert_eq!(day.part_2(&input).unwrap(), answers::expected(2023, 9, 2));
     }
 }